@@ -1,67 +1,97 @@
-use crate::interpolate::interpolate;
-use core::fmt;
+#[cfg(feature = "async")]
+use crate::yield_now::YieldNow;
+use crate::{
+    config::ValueConfig,
+    dac::Dac,
+    error::{ConfigError, DeltaError, ReadError, TransferError},
+    filter_state::FilterState,
+    interpolate::{interpolate_ratio, Interpolation},
+    one_shot_owned::OneShotOwned,
+    reading::{Diagnostic, FullReading, Reading, Trend},
+    table::{is_sorted, Located, Table},
+    window_sum::WindowSum,
+};
+use core::cmp::Ordering;
 use embedded_hal::adc::{Channel, OneShot};
 
-/// Configuration for an `AdcInterpolator`.
-///
-/// - `max_voltage`: The voltage corresponding to the largest value possible for the ADC (mV)
-/// - `precision`: The precision of the ADC in bits (eg. for 10-bit precision, use `10`)
-/// - `voltage_to_values`: An array of tuples of `(voltage in mV, value)` which will be used for the interpolation
-///
-/// # Examples
-///
-/// ```
-/// use adc_interpolator::Config;
-///
-/// let config = Config {
-///     max_voltage: 3300, // 3.3 V
-///     precision: 10,     // 10 bits of precision
-///     voltage_to_values: [
-///         (100, 5),   // 100 mV  -> 5
-///         (500, 10),  // 500 mV  -> 10
-///         (2000, 15), // 2000 mV -> 15
-///     ],
-/// };
-/// ```
-pub struct Config<const LENGTH: usize> {
-    pub max_voltage: u32,
-    pub precision: u32,
-    pub voltage_to_values: [(u32, u32); LENGTH],
+#[derive(Debug)]
+pub struct AdcInterpolator<Pin, Word, const LENGTH: usize, V = u32> {
+    pin: Pin,
+    table: Table<Word, LENGTH, V>,
+    filter: FilterState<Word>,
+    sample_count: u32,
 }
 
-impl<const LENGTH: usize> Config<LENGTH> {
-    fn table<Word>(&self) -> [(Word, u32); LENGTH]
-    where
-        Word: Copy + PartialOrd + TryFrom<u32>,
-        <Word as TryFrom<u32>>::Error: fmt::Debug,
-    {
-        let mut table: [(Word, u32); LENGTH] = [(0.try_into().unwrap(), 0); LENGTH];
+type Error<Adc, ADC, Word, Pin> = nb::Error<<Adc as OneShot<ADC, Word, Pin>>::Error>;
 
-        for (index, (voltage, value)) in self.voltage_to_values.into_iter().enumerate() {
-            let max_adc_value = 2u32.pow(self.precision);
-            let adc_value = voltage * max_adc_value / self.max_voltage;
+/// The number of bytes taken by each point in a serialized table.
+const TABLE_ENTRY_BYTES: usize = 8;
 
-            table[index] = (adc_value.try_into().unwrap(), value);
-        }
+/// A `(numerator, denominator)` fraction.
+type Ratio = (u64, u32);
 
-        table
-    }
-}
+/// A `(nearest, interpolated)` pair of values.
+type Both = (u32, u32);
 
-#[derive(Debug)]
-pub struct AdcInterpolator<Pin, Word, const LENGTH: usize> {
-    pin: Pin,
-    table: [(Word, u32); LENGTH],
-}
+/// A `(value, confidence)` pair.
+type Confident = (u32, u32);
 
-type Error<Adc, ADC, Word, Pin> = nb::Error<<Adc as OneShot<ADC, Word, Pin>>::Error>;
+/// A `(value, uncertainty)` pair.
+type Uncertain = (u32, u32);
+
+/// A `(value, on_node)` pair.
+type OnNode = (u32, bool);
+
+/// A `(tick, value)` pair.
+type Timed = (u32, Option<u32>);
+
+/// A `(value, trend)` pair.
+type Trended = (Option<u32>, Trend);
 
-impl<Pin, Word, const LENGTH: usize> AdcInterpolator<Pin, Word, LENGTH> {
+/// A value, or a diagnostic explaining why there isn't one.
+type Reported<Word> = Result<u32, Diagnostic<Word>>;
+
+impl<Pin, Word, const LENGTH: usize, V> AdcInterpolator<Pin, Word, LENGTH, V> {
     /// Returns an interpolator using the provided `config`.
     ///
     /// The values in `config`'s `voltage_to_values` field must be in
     /// ascending order by voltage or this function will panic when
-    /// running in debug mode.
+    /// running in debug mode. In release mode, every reading from an
+    /// interpolator with an unsorted table falls outside the range of
+    /// the table, rather than giving a wrong value.
+    ///
+    /// The table must have at least two points, or this function will
+    /// fail to compile:
+    ///
+    /// ```compile_fail
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     voltage_to_values: [(100, 40)],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let interpolator: AdcInterpolator<_, u16, 1> = AdcInterpolator::new(pin, config);
+    /// ```
+    ///
+    /// Including an empty table:
+    ///
+    /// ```compile_fail
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config: Config<0> = Config {
+    ///     voltage_to_values: [],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let interpolator: AdcInterpolator<_, u16, 0> = AdcInterpolator::new(pin, config);
+    /// ```
     ///
     /// # Examples
     ///
@@ -83,27 +113,280 @@ impl<Pin, Word, const LENGTH: usize> AdcInterpolator<Pin, Word, LENGTH> {
     ///         (200, 30),
     ///         (300, 10),
     ///     ],
+    ///     ..Default::default()
     /// };
     ///
     /// let interpolator = AdcInterpolator::new(pin, config);
     /// # let interpolator_u16: AdcInterpolator<MockChan0, u16, 3> = interpolator;
-    pub fn new<ADC>(pin: Pin, config: Config<LENGTH>) -> Self
+    pub fn new<ADC>(pin: Pin, config: ValueConfig<LENGTH, V>) -> Self
     where
         Word: Copy + PartialOrd + TryFrom<u32>,
-        <Word as TryFrom<u32>>::Error: fmt::Debug,
         Pin: Channel<ADC>,
+        V: Copy,
     {
         debug_assert!(
-            config
-                .voltage_to_values
-                .windows(2)
-                .all(|w| w[0].0 <= w[1].0),
+            is_sorted(&config),
             "The values in table must be in ascending order by voltage"
         );
 
+        let codes = config.codes().unwrap();
+
+        Self::from_codes(pin, config, codes)
+    }
+
+    /// Returns an interpolator using the provided `config`, or an
+    /// error if `config` is invalid.
+    ///
+    /// Unlike [`new`](AdcInterpolator::new), this doesn't panic if
+    /// `config`'s `precision` isn't less than 32 bits, if the values in
+    /// its `voltage_to_values` field aren't in strictly ascending order
+    /// by voltage, or if the ADC's full-scale code or the code for one
+    /// of them doesn't fit in a `Word`. It also enforces `config`'s
+    /// `min_code_spacing`, rejects adjacent points with the same ADC
+    /// code, which would leave a segment of zero width, and rejects a
+    /// `stuck_after` of less than `2`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config, ConfigError};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 8,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (1000, 10), // code 256 doesn't fit in a u8
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let result: Result<AdcInterpolator<_, u8, 3>, _> = AdcInterpolator::try_new(pin, config);
+    ///
+    /// assert_eq!(result.unwrap_err(), ConfigError::CodeOutOfRange { index: 2 });
+    /// ```
+    pub fn try_new<ADC>(pin: Pin, config: ValueConfig<LENGTH, V>) -> Result<Self, ConfigError>
+    where
+        Word: Copy + TryFrom<u32>,
+        Pin: Channel<ADC>,
+        V: Copy,
+    {
+        config.validate()?;
+        let codes = config.codes()?;
+
+        Ok(Self::from_codes(pin, config, codes))
+    }
+
+    /// Returns an interpolator reading from `pin`, using a copy of
+    /// `table`.
+    ///
+    /// Deriving the ADC codes for a table's points from their voltages
+    /// takes a division per point, so a table can be built once, eg.
+    /// at startup, and used for several interpolators on different
+    /// pins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config, Table};
+    /// # use embedded_hal_mock::adc::{MockChan0, MockChan1};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let table: Table<u16, 3> = config.into();
+    ///
+    /// let first = AdcInterpolator::from_table(MockChan0 {}, table);
+    /// let second = AdcInterpolator::from_table(MockChan1 {}, table);
+    ///
+    /// assert_eq!(first.process_code(614), Some(35));
+    /// assert_eq!(second.process_code(614), Some(35));
+    /// ```
+    pub fn from_table<ADC>(pin: Pin, table: Table<Word, LENGTH, V>) -> Self
+    where
+        Pin: Channel<ADC>,
+    {
+        Self {
+            pin,
+            table,
+            filter: FilterState::default(),
+            sample_count: 0,
+        }
+    }
+
+    /// Returns a new interpolator for `pin`, with the same table as
+    /// this one but with its `max_voltage` replaced by
+    /// `max_voltage`, eg. to account for a reference voltage measured
+    /// per board.
+    ///
+    /// Only the ADC codes for the points in the table are
+    /// recomputed. Returns an error if one of them doesn't fit in a
+    /// `Word`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::adc::{MockChan0, MockChan1};
+    /// #
+    /// # let pin = MockChan0 {};
+    /// # let other_pin = MockChan1 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let template: AdcInterpolator<_, u16, 3> = AdcInterpolator::new(pin, config);
+    /// let interpolator = template.with_reference(other_pin, 1024).unwrap();
+    ///
+    /// assert_eq!(interpolator.process_code(400), Some(40));
+    /// ```
+    pub fn with_reference<NewPin, ADC>(
+        &self,
+        pin: NewPin,
+        max_voltage: u32,
+    ) -> Result<AdcInterpolator<NewPin, Word, LENGTH, V>, ConfigError>
+    where
+        Word: Copy + TryFrom<u32>,
+        NewPin: Channel<ADC>,
+        V: Copy,
+    {
+        let config = self.table.config.with_max_voltage(max_voltage);
+        let table = Table {
+            config,
+            codes: config.codes()?,
+            ..self.table
+        };
+
+        Ok(AdcInterpolator::from_table(pin, table))
+    }
+
+    /// Returns an interpolator using a table serialized by
+    /// [`table_bytes`](AdcInterpolator::table_bytes), without deriving
+    /// the table's ADC codes from `config`.
+    ///
+    /// The points in `config`'s `voltage_to_values` field are replaced
+    /// by those in `bytes`, with their voltages derived from their ADC
+    /// codes. The rest of `config` is used as it is.
+    ///
+    /// Returns an error if `bytes` is too short, if its codes aren't
+    /// in strictly ascending order or are above the ADC's full-scale
+    /// code, if one of its values doesn't fit in `V`, or if the
+    /// resulting configuration is invalid, as
+    /// [`try_new`](AdcInterpolator::try_new) does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [(0, 0); 3],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let bytes = [
+    ///     0x99, 0x01, 0, 0, 40, 0, 0, 0, // code 409 -> 40
+    ///     0x33, 0x03, 0, 0, 30, 0, 0, 0, // code 819 -> 30
+    ///     0xcc, 0x04, 0, 0, 10, 0, 0, 0, // code 1228 -> 10
+    /// ];
+    ///
+    /// let interpolator = AdcInterpolator::from_table_bytes(pin, config, &bytes).unwrap();
+    /// # let interpolator: AdcInterpolator<MockChan0, u16, 3> = interpolator;
+    ///
+    /// assert_eq!(interpolator.process_code(614), Some(35));
+    /// ```
+    pub fn from_table_bytes<ADC>(
+        pin: Pin,
+        mut config: ValueConfig<LENGTH, V>,
+        bytes: &[u8],
+    ) -> Result<Self, ConfigError>
+    where
+        Word: Copy + TryFrom<u32>,
+        Pin: Channel<ADC>,
+        V: Copy + TryFrom<u32>,
+    {
+        if bytes.len() < LENGTH * TABLE_ENTRY_BYTES {
+            return Err(ConfigError::TableTooShort);
+        }
+
+        config.check_precision()?;
+
+        let mut codes = [0; LENGTH];
+
+        for (index, entry) in bytes
+            .chunks_exact(TABLE_ENTRY_BYTES)
+            .take(LENGTH)
+            .enumerate()
+        {
+            let code = u32::from_le_bytes([entry[0], entry[1], entry[2], entry[3]]);
+            let value = u32::from_le_bytes([entry[4], entry[5], entry[6], entry[7]]);
+
+            let value = V::try_from(value).map_err(|_| ConfigError::ValueOutOfRange { index })?;
+
+            codes[index] = code;
+            config.voltage_to_values[index] = (config.voltage(code), value);
+        }
+
+        // the codes are checked rather than the voltages derived from
+        // them, which may round to the same mV for close codes
+        if let Some(index) = (1..LENGTH).find(|index| codes[*index] <= codes[*index - 1]) {
+            return Err(ConfigError::NotAscending { index });
+        }
+
+        if let Some(index) = codes
+            .iter()
+            .position(|&code| code > config.full_scale_code())
+        {
+            return Err(ConfigError::CodeOutOfRange { index });
+        }
+
+        config.check_code_spacing(1..LENGTH, |index| codes[index])?;
+        config.check_inl_correction()?;
+        config.check_stuck_after()?;
+
+        let codes: [Option<Word>; LENGTH] = codes.map(|code| code.try_into().ok());
+
+        if let Some(index) = codes.iter().position(Option::is_none) {
+            return Err(ConfigError::CodeOutOfRange { index });
+        }
+
+        Ok(Self::from_codes(pin, config, codes.map(Option::unwrap)))
+    }
+
+    fn from_codes(pin: Pin, config: ValueConfig<LENGTH, V>, codes: [Word; LENGTH]) -> Self
+    where
+        V: Copy,
+    {
         Self {
             pin,
-            table: config.table(),
+            table: Table::from_codes(config, codes),
+            filter: FilterState::default(),
+            sample_count: 0,
         }
     }
 
@@ -112,9 +395,58 @@ impl<Pin, Word, const LENGTH: usize> AdcInterpolator<Pin, Word, LENGTH> {
         self.pin
     }
 
-    /// Returns a value based on the table, using linear interpolation
-    /// between values in the table if necessary. If `adc_value` falls
-    /// outside the range of the table, returns `Ok(None)`.
+    /// Returns the `(voltage in mV, value)` of the point at `index` in
+    /// the table, or `None` if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let interpolator = AdcInterpolator::new(pin, config);
+    /// # let interpolator: AdcInterpolator<MockChan0, u16, 3> = interpolator;
+    ///
+    /// assert_eq!(interpolator.point(1), Some((200, 30)));
+    /// assert_eq!(interpolator.point(3), None);
+    /// ```
+    pub fn point(&self, index: usize) -> Option<(u32, V)>
+    where
+        V: Copy,
+    {
+        let (voltage, _) = self.table.config.voltage_to_values.get(index)?;
+
+        Some((*voltage, self.table.values[index]))
+    }
+
+    /// Returns a value based on the table, using the configured
+    /// [`Interpolation`] between values in the table if necessary. If
+    /// `adc_value` falls outside the range of the table, returns a
+    /// value based on the configuration's `out_of_range_low` or
+    /// `out_of_range_high`, which are
+    /// [`OutOfRange::None`](crate::OutOfRange::None) by default.
+    ///
+    /// Any word type which converts into an `i64` without loss is
+    /// supported, including signed words such as `i16` and `i32`, so no
+    /// conversion can fail. Since the table's voltages can't be
+    /// negative, negative codes are treated as falling below the range
+    /// of the table. Other word types, such as `u64`, can be read
+    /// through a [`WordAdapter`](crate::WordAdapter) by an interpolator
+    /// with an `i64` word, which returns an error for words that don't
+    /// fit.
     ///
     /// # Examples
     ///
@@ -138,6 +470,7 @@ impl<Pin, Word, const LENGTH: usize> AdcInterpolator<Pin, Word, LENGTH> {
     ///         (200, 30),
     ///         (300, 10),
     ///     ],
+    ///     ..Default::default()
     /// };
     ///
     /// let mut interpolator = AdcInterpolator::new(pin, config);
@@ -150,149 +483,6447 @@ impl<Pin, Word, const LENGTH: usize> AdcInterpolator<Pin, Word, LENGTH> {
         adc: &mut Adc,
     ) -> Result<Option<u32>, Error<Adc, ADC, Word, Pin>>
     where
-        Word: Copy + Into<u32> + PartialEq + PartialOrd,
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
         Pin: Channel<ADC>,
         Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
     {
-        let adc_value = adc.read(&mut self.pin)?;
+        let adc_value = self.sample(adc)?;
 
-        let result = self.table.iter().enumerate().find_map(|(index, (x0, y0))| {
-            let (x1, y1) = self.table.get(index + 1)?;
+        Ok(self.record(self.process_code(adc_value)))
+    }
 
-            if adc_value >= *x0 && adc_value <= *x1 {
-                Some((x0, y0, x1, y1))
-            } else {
-                None
-            }
-        });
+    /// Returns a value based on the table as
+    /// [`read`](AdcInterpolator::read) does, but interpolating with
+    /// `mode` instead of the configured [`Interpolation`] for this
+    /// reading only, eg. to show the value of the nearest point on a
+    /// diagnostic screen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config, Interpolation};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 2] = [
+    /// #     Transaction::read(0, 700),
+    /// #     Transaction::read(0, 700),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// // With voltage at ~171 mV, the nearest point is 200 mV
+    /// assert_eq!(
+    ///     interpolator.read_with_mode(&mut adc, Interpolation::Nearest),
+    ///     Ok(Some(30))
+    /// );
+    /// assert_eq!(interpolator.read(&mut adc), Ok(Some(33)));
+    /// ```
+    pub fn read_with_mode<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+        mode: Interpolation,
+    ) -> Result<Option<u32>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let adc_value = self.sample(adc)?;
 
-        Ok(result.map(|(x0, y0, x1, y1)| {
-            interpolate((*x0).into(), (*x1).into(), *y0, *y1, adc_value.into())
-        }))
+        Ok(self.record(self.table.process_with(adc_value.into(), Some(mode))))
     }
 
-    /// Returns the smallest value that can be returned by
-    /// [`read`](AdcInterpolator::read).
-    pub fn min_value(&self) -> u32 {
-        self.first_value().min(self.last_value())
-    }
+    /// Returns a value based on the table as
+    /// [`read`](AdcInterpolator::read) does, for an ADC which takes the
+    /// pin by value rather than borrowing it.
+    ///
+    /// The pin is moved out of the interpolator for the conversion and
+    /// back in afterwards, so the interpolator is taken by value and
+    /// returned along with the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config, OneShotOwned};
+    /// # use embedded_hal::adc::Channel;
+    /// #
+    /// # struct Pin;
+    /// #
+    /// # impl Channel<()> for Pin {
+    /// #     type ID = u8;
+    /// #
+    /// #     fn channel() -> u8 {
+    /// #         0
+    /// #     }
+    /// # }
+    ///
+    /// struct Adc;
+    ///
+    /// impl OneShotOwned<(), u16, Pin> for Adc {
+    ///     type Error = ();
+    ///
+    ///     fn read(&mut self, pin: Pin) -> (Pin, nb::Result<u16, ()>) {
+    ///         (pin, Ok(614))
+    ///     }
+    /// }
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let interpolator: AdcInterpolator<_, u16, 3> = AdcInterpolator::new(Pin, config);
+    ///
+    /// let (interpolator, value) = interpolator.read_owned(&mut Adc);
+    /// assert_eq!(value, Ok(Some(35)));
+    /// ```
+    pub fn read_owned<Adc, ADC>(
+        mut self,
+        adc: &mut Adc,
+    ) -> (Self, Result<Option<u32>, nb::Error<Adc::Error>>)
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShotOwned<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let (pin, result) = adc.read(self.pin);
+        self.pin = pin;
 
-    /// Returns the largest value that can be returned by
-    /// [`read`](AdcInterpolator::read).
-    pub fn max_value(&self) -> u32 {
-        self.first_value().max(self.last_value())
-    }
+        let result = result.map(|adc_value| {
+            self.note(adc_value);
 
-    fn first_value(&self) -> u32 {
-        self.table.first().unwrap().1
-    }
+            self.record(self.process_code(adc_value))
+        });
 
-    fn last_value(&self) -> u32 {
-        self.table.last().unwrap().1
+        (self, result)
     }
-}
 
-#[cfg(test)]
+    /// Returns a value based on the table for an ADC code which has
+    /// already been sampled, as [`read`](AdcInterpolator::read) would
+    /// if the ADC returned `code`. All of the corrections in the
+    /// configuration are applied, but no ADC is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let interpolator = AdcInterpolator::new(pin, config);
+    /// # let interpolator: AdcInterpolator<MockChan0, u16, 3> = interpolator;
+    ///
+    /// assert_eq!(interpolator.process_code(614), Some(35));
+    /// assert_eq!(interpolator.process_code(100), None);
+    /// ```
+    pub fn process_code(&self, code: Word) -> Option<u32>
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        self.table.process(code.into())
+    }
+
+    /// Returns a value based on the table for a voltage `mv` (mV)
+    /// known from another source, as [`read`](AdcInterpolator::read)
+    /// would for an ADC reading of that voltage. No ADC is used, so
+    /// the INL correction and `plausible_range` in the configuration
+    /// aren't applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let interpolator = AdcInterpolator::new(pin, config);
+    /// # let interpolator: AdcInterpolator<MockChan0, u16, 3> = interpolator;
+    ///
+    /// assert_eq!(interpolator.value_at_voltage(150), Some(35));
+    /// assert_eq!(interpolator.value_at_voltage(50), None);
+    /// ```
+    pub fn value_at_voltage(&self, mv: u32) -> Option<u32>
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        let adc_value = if self.table.config.interpolate_in_voltage {
+            mv.saturating_mul(1000)
+        } else {
+            self.table.config.code(mv)
+        };
+
+        self.table
+            .lookup(adc_value)
+            .or_else(|| self.table.out_of_range(adc_value < self.table.x(0)))
+    }
+
+    /// Returns whether the value for the ADC code `code`, as
+    /// [`process_code`](AdcInterpolator::process_code) computes it, is
+    /// within `tolerance` of `expected`, eg. for a power-on self-test
+    /// against a known reference. Returns `false` if `code` falls
+    /// outside the range of the table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let interpolator = AdcInterpolator::new(pin, config);
+    /// # let interpolator: AdcInterpolator<MockChan0, u16, 3> = interpolator;
+    ///
+    /// assert!(interpolator.self_check(614, 36, 1));
+    /// assert!(!interpolator.self_check(614, 37, 1));
+    /// ```
+    pub fn self_check(&self, code: Word, expected: u32, tolerance: u32) -> bool
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        self.process_code(code)
+            .is_some_and(|value| value.abs_diff(expected) <= tolerance)
+    }
+
+    /// Returns the indices of the two points in the table which
+    /// bracket the ADC code `code`, as `(lower, upper)`, or `None` if
+    /// `code` falls outside the range of the table.
+    ///
+    /// If `code` falls exactly on a point shared by two segments, the
+    /// configured [`Boundary`](crate::Boundary) decides which segment's
+    /// points are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let interpolator = AdcInterpolator::new(pin, config);
+    /// # let interpolator: AdcInterpolator<MockChan0, u16, 3> = interpolator;
+    ///
+    /// // ~250 mV is between the points at 200 mV and 300 mV
+    /// assert_eq!(interpolator.bracket_indices(1024), Some((1, 2)));
+    /// assert_eq!(interpolator.bracket_indices(4000), None);
+    /// ```
+    pub fn bracket_indices(&self, code: Word) -> Option<(usize, usize)>
+    where
+        Word: Copy + Into<i64>,
+    {
+        let adc_value = self.table.position(code)?;
+
+        self.table
+            .segment(adc_value)
+            .map(|index| (index, index + 1))
+    }
+
+    /// Returns whether the ADC code `code` falls within a segment of
+    /// the table whose two points have the same value, where the value
+    /// is constant and can't be mapped back to a single code. Returns
+    /// `false` if `code` falls outside the range of the table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 40),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let interpolator = AdcInterpolator::new(pin, config);
+    /// # let interpolator: AdcInterpolator<MockChan0, u16, 3> = interpolator;
+    ///
+    /// assert!(interpolator.on_plateau(614));
+    /// assert!(!interpolator.on_plateau(1024));
+    /// ```
+    pub fn on_plateau(&self, code: Word) -> bool
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        self.bracket_indices(code).is_some_and(|(lower, upper)| {
+            self.table.node(lower).1.into() == self.table.node(upper).1.into()
+        })
+    }
+
+    /// Returns a value based on the table as
+    /// [`read`](AdcInterpolator::read) does, but as an exact
+    /// `(numerator, denominator)` fraction, before any division is
+    /// done. The numerator is a `u64`, so that it can't overflow for
+    /// large values.
+    ///
+    /// Only [`Interpolation::Linear`] produces fractional values. With
+    /// other interpolation modes, the denominator is always `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 1] = [Transaction::read(0, 500)];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// // 37.78, which `read` would truncate to 37
+    /// assert_eq!(interpolator.read_ratio(&mut adc), Ok(Some((15490, 410))));
+    /// ```
+    pub fn read_ratio<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+    ) -> Result<Option<Ratio>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let code = self.sample(adc)?;
+
+        let (index, adc_value) = match self.locate(code) {
+            Located::Inside { index, adc_value } => (index, adc_value),
+            Located::Outside(value) => return Ok(value.map(|value| (value.into(), 1))),
+        };
+
+        if self.table.interpolation(index) != Interpolation::Linear {
+            let value = self.table.evaluate(index, adc_value, None);
+
+            return Ok(Some((value.into(), 1)));
+        }
+
+        let (x0, y0) = self.table.node(index);
+        let (x1, y1) = self.table.node(index + 1);
+
+        Ok(Some(interpolate_ratio(
+            x0,
+            x1,
+            y0.into(),
+            y1.into(),
+            adc_value,
+        )))
+    }
+
+    /// Reads a value as [`read`](AdcInterpolator::read) does, but as a
+    /// Q16.16 fixed-point number, with 16 fractional bits computed from
+    /// the reading's position within its segment of the table. The
+    /// result is truncated, and saturates at `i32::MAX`.
+    ///
+    /// Only [`Interpolation::Linear`] produces fractional values. With
+    /// other interpolation modes, the fractional bits are always `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 2] = [
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(0, 1126),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// assert_eq!(interpolator.read_q16(&mut adc), Ok(Some(35 << 16)));
+    /// // ~14.99
+    /// assert_eq!(interpolator.read_q16(&mut adc), Ok(Some(982238)));
+    /// ```
+    pub fn read_q16<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+    ) -> Result<Option<i32>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let code = self.sample(adc)?;
+        let saturate = |q16: u128| i32::try_from(q16).unwrap_or(i32::MAX);
+
+        let (index, adc_value) = match self.locate(code) {
+            Located::Inside { index, adc_value } => (index, adc_value),
+            Located::Outside(value) => {
+                return Ok(value.map(|value| saturate(u128::from(value) << 16)))
+            }
+        };
+
+        if self.table.interpolation(index) != Interpolation::Linear {
+            let value = self.table.evaluate(index, adc_value, None);
+
+            return Ok(Some(saturate(u128::from(value) << 16)));
+        }
+
+        let (x0, y0) = self.table.node(index);
+        let (x1, y1) = self.table.node(index + 1);
+
+        let sum = u128::from(y0.into()) * u128::from(x1 - adc_value)
+            + u128::from(y1.into()) * u128::from(adc_value - x0);
+
+        Ok(Some(saturate((sum << 16) / u128::from(x1 - x0))))
+    }
+
+    /// Returns both the value of the point in the table nearest to the
+    /// reading and the value computed using the configured
+    /// [`Interpolation`], as `(nearest, interpolated)`, from a single
+    /// ADC conversion. Readings outside the range of the table are
+    /// handled as [`read`](AdcInterpolator::read) handles them, with
+    /// the same value for both.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 1] = [Transaction::read(0, 700)];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// // With voltage at ~171 mV, the nearest point is 200 mV
+    /// assert_eq!(interpolator.read_both(&mut adc), Ok(Some((30, 33))));
+    /// ```
+    pub fn read_both<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+    ) -> Result<Option<Both>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let code = self.sample(adc)?;
+
+        Ok(match self.locate(code) {
+            Located::Inside { index, adc_value } => Some((
+                self.table.nearest(index, adc_value).into(),
+                self.table.evaluate(index, adc_value, None),
+            )),
+            Located::Outside(value) => value.map(|value| (value, value)),
+        })
+    }
+
+    /// Reads a value as [`read`](AdcInterpolator::read) does, along
+    /// with a confidence score for it, as `(value, confidence)`.
+    ///
+    /// Interpolation is most trustworthy close to the points in the
+    /// table, so the confidence is based on the distance from the
+    /// reading to the nearest point. It ranges from `1000` for a
+    /// reading exactly on a point down to `0` for one halfway between
+    /// two points. A value given to a reading outside the range of the
+    /// table, eg. by `out_of_range_high`, has a confidence of `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 2] = [
+    /// #     Transaction::read(0, 819),
+    /// #     Transaction::read(0, 1126),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// // Exactly on the point at 200 mV
+    /// assert_eq!(interpolator.read_with_confidence(&mut adc), Ok(Some((30, 1000))));
+    /// // A quarter of the way from the point at 300 mV to the one at 200 mV
+    /// assert_eq!(interpolator.read_with_confidence(&mut adc), Ok(Some((15, 502))));
+    /// ```
+    pub fn read_with_confidence<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+    ) -> Result<Option<Confident>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let code = self.sample(adc)?;
+
+        let (index, adc_value) = match self.locate(code) {
+            Located::Inside { index, adc_value } => (index, adc_value),
+            Located::Outside(value) => return Ok(value.map(|value| (value, 0))),
+        };
+
+        let value = self.table.evaluate(index, adc_value, None);
+        let (x0, x1) = (self.table.x(index), self.table.x(index + 1));
+        let distance = (adc_value - x0).min(x1 - adc_value);
+        let confidence = 1000 - u64::from(distance) * 2000 / u64::from(x1 - x0);
+
+        Ok(Some((value, confidence as u32)))
+    }
+
+    /// Reads a value as [`read`](AdcInterpolator::read) does, along
+    /// with an estimate of its uncertainty, as `(value, uncertainty)`.
+    ///
+    /// `code_noise` is the known noise in the ADC codes, which is
+    /// propagated into the units of the values by multiplying it by the
+    /// slope of the table's segment containing the reading, so that
+    /// the uncertainty is larger where the curve is steep. A value
+    /// given to a reading outside the range of the table, eg. by
+    /// `out_of_range_high`, doesn't depend on the code, so its
+    /// uncertainty is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 2] = [
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(0, 1126),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// // The value changes by ~1 for every 41 codes between 100 and 200 mV...
+    /// assert_eq!(interpolator.read_with_uncertainty(&mut adc, 41), Ok(Some((35, 1))));
+    /// // ...and by ~2 between 200 and 300 mV
+    /// assert_eq!(interpolator.read_with_uncertainty(&mut adc, 41), Ok(Some((15, 2))));
+    /// ```
+    pub fn read_with_uncertainty<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+        code_noise: u32,
+    ) -> Result<Option<Uncertain>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let code = self.sample(adc)?;
+
+        let (index, adc_value) = match self.locate(code) {
+            Located::Inside { index, adc_value } => (index, adc_value),
+            Located::Outside(value) => return Ok(value.map(|value| (value, 0))),
+        };
+
+        let value = self.table.evaluate(index, adc_value, None);
+        let run = u64::from(self.table.code(index + 1) - self.table.code(index));
+        let (y0, y1) = (self.table.node(index).1, self.table.node(index + 1).1);
+        let rise = u64::from(y0.into().abs_diff(y1.into()));
+
+        if run == 0 {
+            return Ok(Some((value, 0)));
+        }
+
+        let uncertainty = (u64::from(code_noise) * rise + run / 2) / run;
+
+        Ok(Some((
+            value,
+            u32::try_from(uncertainty).unwrap_or(u32::MAX),
+        )))
+    }
+
+    /// Takes `N` samples from the ADC, discards the `trim` lowest and
+    /// `trim` highest, and returns the value for the average of the
+    /// rest, as [`read`](AdcInterpolator::read) would.
+    ///
+    /// This rejects occasional spikes in the samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `trim * 2 >= N`, since there would be no samples left
+    /// to average.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 4] = [
+    /// #     Transaction::read(0, 612),
+    /// #     Transaction::read(0, 4000),
+    /// #     Transaction::read(0, 616),
+    /// #     Transaction::read(0, 0),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// // The spikes to 4000 and 0 are discarded
+    /// assert_eq!(interpolator.read_trimmed::<_, _, 4>(&mut adc, 1), Ok(Some(35)));
+    /// ```
+    pub fn read_trimmed<Adc, ADC, const N: usize>(
+        &mut self,
+        adc: &mut Adc,
+        trim: usize,
+    ) -> Result<Option<u32>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        assert!(trim * 2 < N, "trim must be less than half of N");
+
+        let mut samples = [0; N];
+
+        for sample in samples.iter_mut() {
+            *sample = self.sample(adc)?.into();
+        }
+
+        samples.sort_unstable();
+
+        let kept = &samples[trim..N - trim];
+        let average = kept.iter().sum::<i64>() / kept.len() as i64;
+
+        Ok(self.record(self.table.process(average)))
+    }
+
+    /// Takes `samples` samples from the ADC and returns the value for
+    /// their average, as [`read`](AdcInterpolator::read) would. Stops
+    /// at the first ADC error and returns it.
+    ///
+    /// The samples are summed in an `i64`, which can't overflow for up
+    /// to `2^31` samples, even of the largest 32-bit code.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 4] = [
+    /// #     Transaction::read(0, 612),
+    /// #     Transaction::read(0, 616),
+    /// #     Transaction::read(0, 610),
+    /// #     Transaction::read(0, 618),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// assert_eq!(interpolator.read_averaged(&mut adc, 4), Ok(Some(35)));
+    /// ```
+    pub fn read_averaged<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+        samples: u32,
+    ) -> Result<Option<u32>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        assert!(samples != 0, "samples must not be zero");
+
+        let mut sum: i64 = 0;
+
+        for _ in 0..samples {
+            let code: i64 = self.sample(adc)?.into();
+            sum += code;
+        }
+
+        Ok(self.record(self.table.process(sum / i64::from(samples))))
+    }
+
+    /// Takes `discard` samples from the ADC and throws them away, then
+    /// takes `average` more and returns the value for their average,
+    /// as [`read_averaged`](AdcInterpolator::read_averaged) does. Stops
+    /// at the first ADC error and returns it.
+    ///
+    /// The first conversions after a multiplexed ADC switches channels
+    /// can be skewed by charge left over from the previous channel, so
+    /// discarding them improves accuracy without needing a delay. The
+    /// discarded samples count towards
+    /// [`sample_count`](AdcInterpolator::sample_count), but not towards
+    /// the noise estimate or the latest codes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `average` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 6] = [
+    /// #     Transaction::read(0, 4000),
+    /// #     Transaction::read(0, 2000),
+    /// #     Transaction::read(0, 612),
+    /// #     Transaction::read(0, 616),
+    /// #     Transaction::read(0, 610),
+    /// #     Transaction::read(0, 618),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// assert_eq!(interpolator.read_with_discard(&mut adc, 2, 4), Ok(Some(35)));
+    /// ```
+    pub fn read_with_discard<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+        discard: usize,
+        average: usize,
+    ) -> Result<Option<u32>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        assert!(average != 0, "average must not be zero");
+
+        for _ in 0..discard {
+            self.discard(adc)?;
+        }
+
+        let mut sum: i64 = 0;
+
+        for _ in 0..average {
+            let code: i64 = self.sample(adc)?.into();
+            sum += code;
+        }
+
+        Ok(self.record(self.table.process(sum / average as i64)))
+    }
+
+    /// Takes `samples` samples from the ADC and returns the value for
+    /// their average, as [`read`](AdcInterpolator::read) would.
+    ///
+    /// While the ADC is busy with a conversion, this yields instead of
+    /// spinning, so that other tasks can run during a long
+    /// acquisition.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 4] = [
+    /// #     Transaction::read(0, 612),
+    /// #     Transaction::read(0, 616),
+    /// #     Transaction::read(0, 610),
+    /// #     Transaction::read(0, 618),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// # let future = async {
+    /// assert_eq!(interpolator.read_averaged_async(&mut adc, 4).await, Ok(Some(35)));
+    /// # };
+    /// # use core::future::Future;
+    /// # let mut future = core::pin::pin!(future);
+    /// # let mut context = core::task::Context::from_waker(std::task::Waker::noop());
+    /// # while future.as_mut().poll(&mut context).is_pending() {}
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn read_averaged_async<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+        samples: u32,
+    ) -> Result<Option<u32>, Adc::Error>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        assert!(samples != 0, "samples must not be zero");
+
+        let mut sum = 0;
+
+        for _ in 0..samples {
+            let code = loop {
+                match self.sample(adc) {
+                    Ok(code) => break code.into(),
+                    Err(nb::Error::WouldBlock) => YieldNow::default().await,
+                    Err(nb::Error::Other(error)) => return Err(error),
+                }
+            };
+
+            sum += code;
+        }
+
+        Ok(self.record(self.table.process(sum / i64::from(samples))))
+    }
+
+    /// Returns a value based on the table as
+    /// [`read`](AdcInterpolator::read) does, but with the ADC code
+    /// clamped to the range of the table first, so that a reading
+    /// outside the range gives the value of the nearest end of the
+    /// table rather than `None`.
+    ///
+    /// `plausible_range` isn't checked, so that a value is always
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 3] = [
+    /// #     Transaction::read(0, 0),
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(0, 4095),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// assert_eq!(interpolator.read_clamped_code(&mut adc), Ok(40));
+    /// assert_eq!(interpolator.read_clamped_code(&mut adc), Ok(35));
+    /// assert_eq!(interpolator.read_clamped_code(&mut adc), Ok(10));
+    /// ```
+    pub fn read_clamped_code<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+    ) -> Result<u32, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let adc_value = self.sample(adc)?;
+
+        let (first, last) = (self.table.x(0), self.table.x(LENGTH - 1));
+        let adc_value = self
+            .table
+            .position(adc_value)
+            .unwrap_or(first)
+            .clamp(first.min(last), first.max(last));
+
+        // the lookup only fails if the table isn't sorted
+        let value = self
+            .table
+            .lookup(adc_value)
+            .unwrap_or_else(|| self.table.first_value());
+        self.record(Some(value));
+
+        Ok(value)
+    }
+
+    /// Reads a value as [`read`](AdcInterpolator::read) does, rounded
+    /// to the nearest multiple of `step`. Values exactly halfway
+    /// between two multiples are rounded up. If `step` is `0`, the
+    /// value isn't rounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 1] = [Transaction::read(0, 700)];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// // With voltage at ~171 mV, the value is 33
+    /// assert_eq!(interpolator.read_quantized(&mut adc, 5), Ok(Some(35)));
+    /// ```
+    pub fn read_quantized<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+        step: u32,
+    ) -> Result<Option<u32>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let value = self.read(adc)?;
+
+        if step == 0 {
+            return Ok(value);
+        }
+
+        Ok(value.map(|value| {
+            let (value, step) = (u64::from(value), u64::from(step));
+            let max = u64::from(u32::MAX) / step * step;
+
+            ((value + step / 2) / step * step).min(max) as u32
+        }))
+    }
+
+    /// Reads a value from a ratiometric sensor, whose output is
+    /// proportional to its supply voltage, cancelling out any
+    /// variation in the supply.
+    ///
+    /// Reads both the interpolator's pin and `supply`, a pin measuring
+    /// the sensor's supply voltage, and looks up
+    /// `sensor_code * (2^precision - 1) / supply_code` in the table,
+    /// which is the code the sensor would produce if its supply were
+    /// at full scale. Returns `Ok(None)` if the supply reads as `0`.
+    /// The sensor's code is checked against `plausible_range` and
+    /// `rail_is_error`, and results outside the range of the table are
+    /// handled as they are by [`read`](AdcInterpolator::read). Both
+    /// conversions count towards
+    /// [`sample_count`](AdcInterpolator::sample_count).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, MockChan1, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 2] = [
+    /// #     Transaction::read(0, 300),
+    /// #     Transaction::read(1, 2000),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    /// # let mut supply = MockChan1 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// // With the supply at about half scale, a code of 300 is
+    /// // equivalent to 614 (150 mV), so the value is 35
+    /// assert_eq!(interpolator.read_ratiometric(&mut adc, &mut supply), Ok(Some(35)));
+    /// ```
+    pub fn read_ratiometric<Adc, ADC, SupplyPin>(
+        &mut self,
+        adc: &mut Adc,
+        supply: &mut SupplyPin,
+    ) -> Result<Option<u32>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        SupplyPin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>
+            + OneShot<ADC, Word, SupplyPin, Error = <Adc as OneShot<ADC, Word, Pin>>::Error>,
+        V: Copy + Into<u32>,
+    {
+        let sensor: i64 = self.sample::<Adc, ADC>(adc)?.into();
+        let supply = self.sample_supply::<Adc, ADC, SupplyPin>(adc, supply)?;
+
+        let Some(supply) = self.table.corrected(supply).filter(|&supply| supply != 0) else {
+            return Ok(None);
+        };
+
+        let config = &self.table.config;
+        let located = match u32::try_from(sensor) {
+            Ok(code) if config.plausible(sensor) && !config.railed(sensor) => {
+                let ratio = u64::from(config.correct(code)) * u64::from(config.full_scale_code())
+                    / u64::from(supply);
+
+                self.table
+                    .locate_corrected(u32::try_from(ratio).unwrap_or(u32::MAX))
+            }
+            _ => self.table.locate(sensor),
+        };
+
+        Ok(self.record(self.table.resolve(located, None)))
+    }
+
+    /// Performs `N` consecutive reads as
+    /// [`read`](AdcInterpolator::read) does, returning the results in
+    /// order. Stops at the first ADC error and returns it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 3] = [
+    /// #     Transaction::read(0, 409),
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(0, 0),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// assert_eq!(interpolator.read_n(&mut adc), Ok([Some(40), Some(35), None]));
+    /// ```
+    pub fn read_n<Adc, ADC, const N: usize>(
+        &mut self,
+        adc: &mut Adc,
+    ) -> Result<[Option<u32>; N], Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let mut values = [None; N];
+
+        for value in values.iter_mut() {
+            *value = self.read(adc)?;
+        }
+
+        Ok(values)
+    }
+
+    /// Returns a [`Reading`] based on the table, as
+    /// [`read`](AdcInterpolator::read) does, but distinguishing
+    /// readings below the table from readings above it.
+    ///
+    /// If the ADC returns `0` or its highest possible code
+    /// (`2^precision - 1`), returns [`Reading::Saturated`] regardless
+    /// of the table, since the ADC can't tell how far beyond its range
+    /// the input is. If the code is outside the configuration's
+    /// `plausible_range`, returns [`Reading::Implausible`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config, Reading};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 3] = [
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(0, 100),
+    /// #     Transaction::read(0, 4095),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// assert_eq!(interpolator.read_detailed(&mut adc), Ok(Reading::InRange(35)));
+    /// assert_eq!(interpolator.read_detailed(&mut adc), Ok(Reading::Below));
+    /// assert_eq!(interpolator.read_detailed(&mut adc), Ok(Reading::Saturated));
+    /// ```
+    pub fn read_detailed<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+    ) -> Result<Reading, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let adc_value = self.sample(adc)?;
+        let reading = self.table.reading(adc_value);
+        self.record(reading.value());
+
+        Ok(reading)
+    }
+
+    /// Returns the voltage (mV) read by the ADC, independent of the
+    /// table. The configuration's `inl_correction` is applied to the
+    /// ADC code before it is converted. Negative codes are read as
+    /// 0 mV.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 1] = [Transaction::read(0, 2048)];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 3300,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// assert_eq!(interpolator.read_voltage(&mut adc), Ok(1650));
+    /// ```
+    pub fn read_voltage<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+    ) -> Result<u32, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+    {
+        let adc_value = self.sample(adc)?;
+
+        Ok(self
+            .table
+            .config
+            .voltage(self.table.corrected(adc_value).unwrap_or(0)))
+    }
+
+    /// Returns the voltage (µV) read by the ADC, independent of the
+    /// table. Like [`read_voltage`](AdcInterpolator::read_voltage),
+    /// but with enough resolution for high-precision ADCs, where a
+    /// single code is much smaller than 1 mV.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 1] = [Transaction::read(0, 1)];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 3300,
+    ///     precision: 16,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// assert_eq!(interpolator.read_microvolts(&mut adc), Ok(50));
+    /// ```
+    pub fn read_microvolts<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+    ) -> Result<u32, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+    {
+        let adc_value = self.sample(adc)?;
+
+        Ok(self
+            .table
+            .config
+            .microvolts(self.table.corrected(adc_value).unwrap_or(0)))
+    }
+
+    /// Returns the raw code, voltage (mV) and value from a single ADC
+    /// conversion, as returned separately by
+    /// [`read_voltage`](AdcInterpolator::read_voltage) and
+    /// [`read`](AdcInterpolator::read), so that they are consistent
+    /// with each other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config, FullReading};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 1] = [Transaction::read(0, 614)];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// assert_eq!(
+    ///     interpolator.read_full(&mut adc),
+    ///     Ok(FullReading {
+    ///         code: 614,
+    ///         voltage_mv: 149,
+    ///         value: Some(35),
+    ///     })
+    /// );
+    /// ```
+    pub fn read_full<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+    ) -> Result<FullReading<Word>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let code = self.sample(adc)?;
+        let value = self.record(self.process_code(code));
+
+        Ok(FullReading {
+            code,
+            voltage_mv: self
+                .table
+                .config
+                .voltage(self.table.corrected(code).unwrap_or(0)),
+            value,
+        })
+    }
+
+    /// Returns a value based on the table, as
+    /// [`read`](AdcInterpolator::read) does, along with whether the ADC
+    /// code is exactly the code of one of the table's points, rather
+    /// than being interpolated between them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 2] = [
+    /// #     Transaction::read(0, 819),
+    /// #     Transaction::read(0, 614),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// // 819 is the code for 200 mV
+    /// assert_eq!(interpolator.read_node(&mut adc), Ok(Some((30, true))));
+    /// assert_eq!(interpolator.read_node(&mut adc), Ok(Some((35, false))));
+    /// ```
+    pub fn read_node<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+    ) -> Result<Option<OnNode>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let adc_value = self.sample(adc)?;
+        let on_node = self.table.codes.contains(&adc_value);
+
+        Ok(self
+            .record(self.process_code(adc_value))
+            .map(|value| (value, on_node)))
+    }
+
+    /// Returns the value of a point in the table, selected according
+    /// to the configured [`Interpolation`]. If `adc_value` falls
+    /// outside the range of the table, or is outside the
+    /// configuration's `plausible_range` or railed while
+    /// `rail_is_error` is set, returns `Ok(None)`.
+    ///
+    /// Unlike [`read`](AdcInterpolator::read), this works with values
+    /// of any type, since no arithmetic is done on them. Values which
+    /// can't be interpolated are selected as if
+    /// [`Interpolation::Linear`] were [`Interpolation::Nearest`].
+    ///
+    /// This makes it possible to classify voltages into bands, such as
+    /// the variants of an enum. With [`Interpolation::Floor`], each
+    /// point starts a band which extends up to the next point, so the
+    /// last point marks the top of the last band.
+    ///
+    /// Methods which interpolate, such as `read`, require values which
+    /// can be converted into a `u32`, so they can't be used with
+    /// values such as enums:
+    ///
+    /// ```compile_fail
+    /// use adc_interpolator::{AdcInterpolator, ValueConfig};
+    /// # use embedded_hal_mock::adc::{Mock, MockChan0, Transaction};
+    /// #
+    /// # let expectations: [Transaction<u16>; 1] = [Transaction::read(0, 1300)];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// #[derive(Clone, Copy, Default)]
+    /// enum Level {
+    ///     #[default]
+    ///     Low,
+    ///     High,
+    /// }
+    ///
+    /// let config = ValueConfig::from([(0, Level::Low), (500, Level::High)]);
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// interpolator.read(&mut adc);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Interpolation, ValueConfig};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 1] = [Transaction::read(0, 1300)];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    /// enum Gear {
+    ///     #[default]
+    ///     Park,
+    ///     Reverse,
+    ///     Neutral,
+    ///     Drive,
+    /// }
+    ///
+    /// let config = ValueConfig {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (0, Gear::Park),
+    ///         (250, Gear::Reverse),
+    ///         (500, Gear::Neutral),
+    ///         (750, Gear::Drive),
+    ///     ],
+    ///     interpolation: Interpolation::Nearest,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// // With voltage at ~317 mV, the nearest point is 250 mV
+    /// assert_eq!(interpolator.read_value(&mut adc), Ok(Some(Gear::Reverse)));
+    /// ```
+    pub fn read_value<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+    ) -> Result<Option<V>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy,
+    {
+        let adc_value = self.sample(adc)?;
+        let code = adc_value.into();
+
+        if !self.table.config.plausible(code) || self.table.config.railed(code) {
+            return Ok(None);
+        }
+
+        let Some(adc_value) = self.table.position(adc_value) else {
+            return Ok(None);
+        };
+
+        Ok(self
+            .table
+            .segment(adc_value)
+            .map(|index| self.table.select(index, adc_value)))
+    }
+
+    /// Replaces the point at `index` in the table with `(voltage_mv,
+    /// value)`.
+    ///
+    /// Returns an error, leaving the table unchanged, if there is no
+    /// point at `index`, if the ADC code for `voltage_mv` doesn't fit
+    /// in a `Word`, or if the new point isn't valid alongside its
+    /// neighbours, as [`try_new`](AdcInterpolator::try_new) checks, ie.
+    /// if it isn't in strictly ascending order by voltage or the
+    /// points are closer together than `min_code_spacing`. Only the
+    /// segments on either side of the point are checked, so that an
+    /// invalid table can be corrected one point at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config, ConfigError};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    /// # let _: &AdcInterpolator<MockChan0, u16, 3> = &interpolator;
+    ///
+    /// assert_eq!(interpolator.set_point(1, 250, 25), Ok(()));
+    /// assert_eq!(interpolator.point(1), Some((250, 25)));
+    ///
+    /// assert_eq!(
+    ///     interpolator.set_point(1, 350, 25),
+    ///     Err(ConfigError::NotAscending { index: 2 })
+    /// );
+    /// assert_eq!(interpolator.point(1), Some((250, 25)));
+    /// ```
+    pub fn set_point(&mut self, index: usize, voltage_mv: u32, value: V) -> Result<(), ConfigError>
+    where
+        Word: Copy + TryFrom<u32>,
+    {
+        if index >= LENGTH {
+            return Err(ConfigError::IndexOutOfRange { index });
+        }
+
+        let code = self
+            .table
+            .config
+            .code(voltage_mv)
+            .try_into()
+            .map_err(|_| ConfigError::CodeOutOfRange { index })?;
+
+        let mut config = self.table.config;
+        config.voltage_to_values[index].0 = voltage_mv;
+        config.check_segments(index.max(1)..(index + 2).min(LENGTH))?;
+
+        self.table.config = config;
+        self.table.values[index] = value;
+        self.table.codes[index] = code;
+        self.filter.hysteresis = None;
+        self.table.sorted = is_sorted(&self.table.config);
+
+        Ok(())
+    }
+
+    /// Replaces the precision (bits) of the ADC, eg. after switching an
+    /// ADC which supports several resolutions to a different one, and
+    /// recomputes the ADC codes for the points in the table from their
+    /// voltages.
+    ///
+    /// Returns an error, leaving the table unchanged, if `precision`
+    /// isn't less than 32, or if the new configuration is invalid, as
+    /// [`try_new`](AdcInterpolator::try_new) does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config, ConfigError};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 10,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    /// # let _: &AdcInterpolator<MockChan0, u16, 3> = &interpolator;
+    ///
+    /// assert_eq!(interpolator.set_precision(12), Ok(()));
+    /// // With voltage at 150 mV, the value is 35
+    /// assert_eq!(interpolator.process_code(614), Some(35));
+    ///
+    /// assert_eq!(
+    ///     interpolator.set_precision(32),
+    ///     Err(ConfigError::InvalidPrecision { precision: 32 })
+    /// );
+    /// ```
+    pub fn set_precision(&mut self, precision: u32) -> Result<(), ConfigError>
+    where
+        Word: Copy + TryFrom<u32>,
+        V: Copy,
+    {
+        let config = self.table.config.with_precision(precision);
+        config.validate()?;
+
+        self.table.codes = config.codes()?;
+        self.table.config = config;
+        self.filter.hysteresis = None;
+        self.filter.noise = Default::default();
+
+        Ok(())
+    }
+
+    /// Shifts every value in the table by `delta`, and so every value
+    /// read afterwards.
+    ///
+    /// Values saturate at `0` and `u32::MAX` rather than wrapping.
+    /// Returns an error, leaving the table unchanged, if a shifted
+    /// value doesn't fit in `V`, eg. a value above `u16::MAX` for
+    /// `u16` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    /// # let _: &AdcInterpolator<MockChan0, u16, 3> = &interpolator;
+    ///
+    /// assert_eq!(interpolator.offset_output(-15), Ok(()));
+    ///
+    /// assert_eq!(interpolator.point(0), Some((100, 25)));
+    /// assert_eq!(interpolator.point(2), Some((300, 0)));
+    /// ```
+    pub fn offset_output(&mut self, delta: i32) -> Result<(), ConfigError>
+    where
+        V: Copy + Into<u32> + TryFrom<u32>,
+    {
+        let values: [Option<V>; LENGTH] = self
+            .table
+            .values
+            .map(|value| V::try_from(value.into().saturating_add_signed(delta)).ok());
+
+        if let Some(index) = values.iter().position(Option::is_none) {
+            return Err(ConfigError::ValueOutOfRange { index });
+        }
+
+        self.table.values = values.map(Option::unwrap);
+        self.filter.hysteresis = None;
+
+        Ok(())
+    }
+
+    /// Reads a value as [`read`](AdcInterpolator::read) does, adds
+    /// `value * dt` to an internal accumulator and returns the
+    /// accumulated total.
+    ///
+    /// Readings outside the range of the table do not contribute to
+    /// the total. The accumulator saturates at `u64::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 2] = [
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(0, 614),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// // With voltage at 150 mV, the value is 35
+    /// assert_eq!(interpolator.read_accumulate(&mut adc, 10), Ok(350));
+    /// assert_eq!(interpolator.read_accumulate(&mut adc, 10), Ok(700));
+    /// ```
+    pub fn read_accumulate<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+        dt: u32,
+    ) -> Result<u64, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        if let Some(value) = self.read(adc)? {
+            self.filter.accumulator = self
+                .filter
+                .accumulator
+                .saturating_add(u64::from(value) * u64::from(dt));
+        }
+
+        Ok(self.filter.accumulator)
+    }
+
+    /// Resets the total accumulated by
+    /// [`read_accumulate`](AdcInterpolator::read_accumulate) to zero.
+    pub fn reset_accumulator(&mut self) {
+        self.filter.accumulator = 0;
+    }
+
+    /// Returns the number of codes read from the ADC by the
+    /// interpolator's `read*` methods, eg. `N` for each call to
+    /// [`read_n`](AdcInterpolator::read_n). The count wraps around
+    /// after `u32::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 3] = [
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(0, 614),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// interpolator.read(&mut adc).unwrap();
+    /// interpolator.read_voltage(&mut adc).unwrap();
+    /// assert_eq!(interpolator.sample_count(), 2);
+    ///
+    /// interpolator.reset_count();
+    /// interpolator.read(&mut adc).unwrap();
+    /// assert_eq!(interpolator.sample_count(), 1);
+    /// ```
+    pub fn sample_count(&self) -> u32 {
+        self.sample_count
+    }
+
+    /// Resets the count returned by
+    /// [`sample_count`](AdcInterpolator::sample_count) to zero.
+    pub fn reset_count(&mut self) {
+        self.sample_count = 0;
+    }
+
+    /// Reads a value as [`read`](AdcInterpolator::read) does, but only
+    /// looks it up in the table if the ADC code differs from the code
+    /// of the last lookup by more than `band`. Otherwise, returns the
+    /// value of the last lookup.
+    ///
+    /// This avoids recomputing the value, and keeps it steady, while
+    /// the ADC code jitters by a few LSBs on a noisy input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 3] = [
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(0, 655),
+    /// #     Transaction::read(0, 660),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// assert_eq!(interpolator.read_code_hysteresis(&mut adc, 45), Ok(Some(35)));
+    /// // A change of 41 codes is within the band, so the value is unchanged
+    /// assert_eq!(interpolator.read_code_hysteresis(&mut adc, 45), Ok(Some(35)));
+    /// assert_eq!(interpolator.read_code_hysteresis(&mut adc, 45), Ok(Some(34)));
+    /// ```
+    pub fn read_code_hysteresis<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+        band: Word,
+    ) -> Result<Option<u32>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let adc_value = self.sample(adc)?;
+
+        if !self.table.config.plausible(adc_value.into()) {
+            return Ok(None);
+        }
+
+        if let Some((code, value)) = self.filter.hysteresis {
+            if adc_value.into().abs_diff(code.into()) <= band.into().unsigned_abs() {
+                return Ok(self.record(value));
+            }
+        }
+
+        let value = self.table.process(adc_value.into());
+        self.filter.hysteresis = Some((adc_value, value));
+
+        Ok(self.record(value))
+    }
+
+    /// Reads a value as [`read`](AdcInterpolator::read) does, returning
+    /// an error if it differs from the previous value read by this
+    /// method by more than `max_delta`.
+    ///
+    /// Every value within the range of the table becomes the previous
+    /// value for the next call, including one which is rejected, so a
+    /// genuine step change is only reported once. Readings outside the
+    /// range of the table are returned as `Ok(None)` and don't change
+    /// the previous value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config, DeltaError};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 3] = [
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(0, 660),
+    /// #     Transaction::read(0, 1228),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// assert_eq!(interpolator.read_checked_delta(&mut adc, 5), Ok(Some(35)));
+    /// assert_eq!(interpolator.read_checked_delta(&mut adc, 5), Ok(Some(34)));
+    /// assert_eq!(
+    ///     interpolator.read_checked_delta(&mut adc, 5),
+    ///     Err(DeltaError::Exceeded { previous: 34, current: 10 })
+    /// );
+    /// ```
+    pub fn read_checked_delta<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+        max_delta: u32,
+    ) -> Result<Option<u32>, DeltaError<Adc::Error>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let current = match self.read(adc)? {
+            Some(current) => current,
+            None => return Ok(None),
+        };
+
+        match self.filter.previous.replace(current) {
+            Some(previous) if previous.abs_diff(current) > max_delta => {
+                Err(DeltaError::Exceeded { previous, current })
+            }
+            _ => Ok(Some(current)),
+        }
+    }
+
+    /// Reads a value as [`read`](AdcInterpolator::read) does, but
+    /// returns a [`Diagnostic`] instead of `None`, with the raw code,
+    /// the range of codes covered by the table, and where the reading
+    /// fell, eg. to find out why a sensor isn't reading during
+    /// bring-up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config, Diagnostic, Reading};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 2] = [
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(0, 4000),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// assert_eq!(interpolator.read_or_report(&mut adc), Ok(Ok(35)));
+    /// assert_eq!(
+    ///     interpolator.read_or_report(&mut adc),
+    ///     Ok(Err(Diagnostic {
+    ///         code: 4000,
+    ///         table_range: (409, 1228),
+    ///         reading: Reading::Above,
+    ///     }))
+    /// );
+    /// ```
+    pub fn read_or_report<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+    ) -> Result<Reported<Word>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let code = self.sample(adc)?;
+
+        Ok(self
+            .record(self.process_code(code))
+            .ok_or_else(|| Diagnostic {
+                code,
+                table_range: (self.table.codes[0], self.table.codes[LENGTH - 1]),
+                reading: self.table.reading(code),
+            }))
+    }
+
+    /// Reads a value as [`read`](AdcInterpolator::read) does, but
+    /// returns [`ReadError::OutOfRange`] instead of `None` if the
+    /// reading is outside the range of the table, so that both kinds
+    /// of failure can be propagated with `?`.
+    ///
+    /// If the configuration's `rail_is_error` is set, returns
+    /// [`ReadError::Railed`] if the ADC returns exactly `0` or its
+    /// full-scale code. If its `stuck_after` is set, returns
+    /// [`ReadError::Stuck`] if the ADC has returned the same code that
+    /// many times in a row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config, ReadError};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 2] = [
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(0, 4000),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// assert_eq!(interpolator.read_required(&mut adc), Ok(35));
+    /// assert_eq!(interpolator.read_required(&mut adc), Err(ReadError::OutOfRange));
+    /// ```
+    pub fn read_required<Adc, ADC>(&mut self, adc: &mut Adc) -> Result<u32, ReadError<Adc::Error>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let adc_value = self.sample(adc)?;
+        let value = self.record(self.process_code(adc_value));
+
+        if self.table.config.railed(adc_value.into()) {
+            return Err(ReadError::Railed);
+        }
+
+        if let Some(stuck_after) = self.table.config.stuck_after {
+            if self.filter.repeats >= stuck_after.saturating_sub(1) {
+                return Err(ReadError::Stuck);
+            }
+        }
+
+        value.ok_or(ReadError::OutOfRange)
+    }
+
+    /// Reads a value as [`read`](AdcInterpolator::read) does, and
+    /// returns its rate of change since the previous value read by
+    /// this method, as `(value - previous) * 1000 / dt`, saturating at
+    /// the bounds of `i32`.
+    ///
+    /// `dt` is the time since the previous reading, eg. in ms for a
+    /// rate in units per second. Returns `Ok(None)` on the first call,
+    /// when there is no previous value, or if `dt` is `0`. Readings
+    /// outside the range of the table are returned as `Ok(None)` and
+    /// don't change the previous value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 2] = [
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(0, 1228),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// assert_eq!(interpolator.read_rate(&mut adc, 100), Ok(None));
+    /// // The value fell from 35 to 10 in 100 ms
+    /// assert_eq!(interpolator.read_rate(&mut adc, 100), Ok(Some(-250)));
+    /// ```
+    pub fn read_rate<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+        dt: u32,
+    ) -> Result<Option<i32>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let current = match self.read(adc)? {
+            Some(current) => current,
+            None => return Ok(None),
+        };
+
+        Ok(self
+            .filter
+            .rate_previous
+            .replace(current)
+            .filter(|_| dt != 0)
+            .map(|previous| {
+                let rate = (i64::from(current) - i64::from(previous)) * 1000 / i64::from(dt);
+
+                rate.clamp(i32::MIN.into(), i32::MAX.into()) as i32
+            }))
+    }
+
+    /// Reads a value as [`read`](AdcInterpolator::read) does, adds it
+    /// to `window`, and returns the sum of the last `K` values in the
+    /// window, eg. to approximate the flow over a time window for a
+    /// flow sensor.
+    ///
+    /// Readings outside the range of the table are returned as
+    /// `Ok(None)` and aren't added to the window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config, WindowSum};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 3] = [
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(0, 819),
+    /// #     Transaction::read(0, 1228),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    /// let mut window = WindowSum::<2>::new();
+    ///
+    /// assert_eq!(interpolator.read_window_sum(&mut adc, &mut window), Ok(Some(35)));
+    /// assert_eq!(interpolator.read_window_sum(&mut adc, &mut window), Ok(Some(65)));
+    /// // The first value has left the window
+    /// assert_eq!(interpolator.read_window_sum(&mut adc, &mut window), Ok(Some(40)));
+    /// ```
+    pub fn read_window_sum<Adc, ADC, const K: usize>(
+        &mut self,
+        adc: &mut Adc,
+        window: &mut WindowSum<K>,
+    ) -> Result<Option<u32>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        Ok(self.read(adc)?.map(|value| {
+            window.push(value);
+            window.sum()
+        }))
+    }
+
+    /// Reads a value as [`read`](AdcInterpolator::read) does, along
+    /// with a timestamp from `now`, which is called once the conversion
+    /// is complete, as `(tick, value)`.
+    ///
+    /// Taking a closure allows any clock, such as a SysTick counter, to
+    /// be used for the timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 2] = [
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(0, 1228),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    /// let mut ticks = 0;
+    /// let mut now = || {
+    ///     ticks += 10;
+    ///     ticks
+    /// };
+    ///
+    /// assert_eq!(interpolator.read_timed(&mut adc, &mut now), Ok((10, Some(35))));
+    /// assert_eq!(interpolator.read_timed(&mut adc, &mut now), Ok((20, Some(10))));
+    /// ```
+    pub fn read_timed<Adc, ADC, T>(
+        &mut self,
+        adc: &mut Adc,
+        mut now: T,
+    ) -> Result<Timed, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+        T: FnMut() -> u32,
+    {
+        let value = self.read(adc)?;
+
+        Ok((now(), value))
+    }
+
+    /// Reads a value as [`read`](AdcInterpolator::read) does, scales it
+    /// from the range of the table's values to the range of `dac`, and
+    /// writes it to `dac`, eg. to output a reading through a
+    /// calibration curve. Returns the code written to `dac`.
+    ///
+    /// The smallest value in the table is written as `0`, and the
+    /// largest as the DAC's [`max_code`](Dac::max_code). Nothing is
+    /// written, and `Ok(None)` is returned, only if the reading has no
+    /// value, eg. if it is outside the range of the table and the
+    /// configuration's `out_of_range_low` or `out_of_range_high` is
+    /// [`OutOfRange::None`](crate::OutOfRange::None). Values given by
+    /// the other [`OutOfRange`](crate::OutOfRange) options are scaled
+    /// and written like any other, saturating at the ends of the DAC's
+    /// range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config, Dac};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 1] = [Transaction::read(0, 614)];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// struct Output(u32);
+    ///
+    /// impl Dac for Output {
+    ///     type Error = ();
+    ///
+    ///     fn max_code(&self) -> u32 {
+    ///         1023
+    ///     }
+    ///
+    ///     fn write(&mut self, code: u32) -> nb::Result<(), ()> {
+    ///         self.0 = code;
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    /// let mut dac = Output(0);
+    ///
+    /// // The value is 35, 5/6 of the way from 10 to 40
+    /// assert_eq!(interpolator.transfer(&mut adc, &mut dac), Ok(Some(852)));
+    /// assert_eq!(dac.0, 852);
+    /// ```
+    pub fn transfer<Adc, ADC, D>(
+        &mut self,
+        adc: &mut Adc,
+        dac: &mut D,
+    ) -> Result<Option<u32>, TransferError<Adc::Error, D::Error>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+        D: Dac,
+    {
+        let Some(value) = self.read(adc).map_err(TransferError::Adc)? else {
+            return Ok(None);
+        };
+
+        let (min, max) = self.value_range();
+        let code = match max - min {
+            0 => 0,
+            span => {
+                let offset = u64::from(value.clamp(min, max) - min);
+
+                (offset * u64::from(dac.max_code()) / u64::from(span)) as u32
+            }
+        };
+
+        dac.write(code).map_err(TransferError::Dac)?;
+
+        Ok(Some(code))
+    }
+
+    /// Reads a value as [`read`](AdcInterpolator::read) does, but
+    /// converts an error from the ADC using `f`, eg. into an
+    /// application's own error type.
+    ///
+    /// `f` is given the [`nb::Error`], so that an ADC which would block
+    /// can be told apart from one which failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// # use std::io::ErrorKind;
+    /// #
+    /// # let expectations: [Transaction<u16>; 1] = [
+    /// #     Transaction::read(0, 0).with_error(MockError::Io(ErrorKind::InvalidData)),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum AppError {
+    ///     Sensor,
+    /// }
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// assert_eq!(
+    ///     interpolator.read_map_err(&mut adc, |_| AppError::Sensor),
+    ///     Err(AppError::Sensor)
+    /// );
+    /// ```
+    pub fn read_map_err<Adc, ADC, F, E2>(&mut self, adc: &mut Adc, f: F) -> Result<Option<u32>, E2>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+        F: FnOnce(nb::Error<Adc::Error>) -> E2,
+    {
+        self.read(adc).map_err(f)
+    }
+
+    /// Reads a value as [`read`](AdcInterpolator::read) does, after
+    /// calling `select`, eg. to set the address lines of an external
+    /// analog multiplexer so that the pin is connected to the right
+    /// sensor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 1] = [Transaction::read(0, 614)];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    /// let mut address = 0;
+    ///
+    /// assert_eq!(interpolator.read_muxed(&mut adc, || address = 3), Ok(Some(35)));
+    /// assert_eq!(address, 3);
+    /// ```
+    pub fn read_muxed<Adc, ADC, S>(
+        &mut self,
+        adc: &mut Adc,
+        mut select: S,
+    ) -> Result<Option<u32>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+        S: FnMut(),
+    {
+        select();
+
+        self.read(adc)
+    }
+
+    /// Reads a value as [`read`](AdcInterpolator::read) does, along
+    /// with the [`Trend`] from the previous value read, as `(value,
+    /// trend)`, eg. to show an arrow next to the value.
+    ///
+    /// The trend is [`Trend::Steady`] if there is no previous value,
+    /// or if the reading is outside the range of the table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config, Trend};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 2] = [
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(0, 1228),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// assert_eq!(interpolator.read_trend(&mut adc), Ok((Some(35), Trend::Steady)));
+    /// assert_eq!(interpolator.read_trend(&mut adc), Ok((Some(10), Trend::Falling)));
+    /// ```
+    pub fn read_trend<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+    ) -> Result<Trended, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let previous = self.filter.latest;
+        let value = self.read(adc)?;
+
+        let trend = match (previous, value) {
+            (Some(previous), Some(value)) if value > previous => Trend::Rising,
+            (Some(previous), Some(value)) if value < previous => Trend::Falling,
+            _ => Trend::Steady,
+        };
+
+        Ok((value, trend))
+    }
+
+    /// Serializes the table's points as `(ADC code, value)` pairs into
+    /// `out`, for loading with
+    /// [`from_table_bytes`](AdcInterpolator::from_table_bytes).
+    ///
+    /// Each point takes 8 bytes: its code followed by its value, each
+    /// as a little-endian `u32`. Returns the number of bytes written,
+    /// or `0` if `out` is too short to hold the whole table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let interpolator = AdcInterpolator::new(pin, config);
+    /// # let interpolator: AdcInterpolator<MockChan0, u16, 3> = interpolator;
+    ///
+    /// let mut bytes = [0; 24];
+    ///
+    /// assert_eq!(interpolator.table_bytes(&mut bytes), 24);
+    /// assert_eq!(bytes[..8], [0x99, 0x01, 0, 0, 40, 0, 0, 0]);
+    /// ```
+    pub fn table_bytes(&self, out: &mut [u8]) -> usize
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        let len = LENGTH * TABLE_ENTRY_BYTES;
+
+        if out.len() < len {
+            return 0;
+        }
+
+        for (index, entry) in out
+            .chunks_exact_mut(TABLE_ENTRY_BYTES)
+            .take(LENGTH)
+            .enumerate()
+        {
+            let value: u32 = self.table.values[index].into();
+
+            entry[..4].copy_from_slice(&self.table.code(index).to_le_bytes());
+            entry[4..].copy_from_slice(&value.to_le_bytes());
+        }
+
+        len
+    }
+
+    /// Looks up each ADC code in `codes` as
+    /// [`read_detailed`](AdcInterpolator::read_detailed) does, writing
+    /// the results to the corresponding elements of `readings`.
+    ///
+    /// Only the first `min(codes.len(), readings.len())` codes are
+    /// processed. Returns the number of readings written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config, Reading};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let interpolator = AdcInterpolator::new(pin, config);
+    /// # let interpolator: AdcInterpolator<MockChan0, u16, 3> = interpolator;
+    ///
+    /// let codes: [u16; 3] = [100, 614, 2000];
+    /// let mut readings = [Reading::Below; 3];
+    ///
+    /// assert_eq!(interpolator.interpolate_codes(&codes, &mut readings), 3);
+    /// assert_eq!(readings, [Reading::Below, Reading::InRange(35), Reading::Above]);
+    /// ```
+    pub fn interpolate_codes(&self, codes: &[Word], readings: &mut [Reading]) -> usize
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        let mut count = 0;
+
+        for (code, reading) in codes.iter().zip(readings.iter_mut()) {
+            *reading = self.table.reading(*code);
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Returns an iterator which lazily looks up each ADC code from
+    /// `codes` as [`process_code`](AdcInterpolator::process_code) does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let interpolator = AdcInterpolator::new(pin, config);
+    /// # let interpolator: AdcInterpolator<MockChan0, u16, 3> = interpolator;
+    ///
+    /// let codes: [u16; 3] = [100, 614, 2000];
+    /// let sum: u32 = interpolator.interpolate_iter(codes).flatten().sum();
+    ///
+    /// assert_eq!(sum, 35);
+    /// ```
+    pub fn interpolate_iter<'a, I>(&'a self, codes: I) -> impl Iterator<Item = Option<u32>> + 'a
+    where
+        I: IntoIterator<Item = Word>,
+        I::IntoIter: 'a,
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        codes.into_iter().map(|code| self.process_code(code))
+    }
+
+    /// Returns a function which maps ADC codes to values, as
+    /// [`process_code`](AdcInterpolator::process_code) does, so that the
+    /// mapping can be passed to code which is generic over functions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let interpolator = AdcInterpolator::new(pin, config);
+    /// # let interpolator: AdcInterpolator<MockChan0, u16, 3> = interpolator;
+    ///
+    /// fn apply(f: impl Fn(u16) -> Option<u32>) -> Option<u32> {
+    ///     f(614)
+    /// }
+    ///
+    /// assert_eq!(apply(interpolator.lookup_fn()), Some(35));
+    /// ```
+    pub fn lookup_fn(&self) -> impl Fn(Word) -> Option<u32> + '_
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        move |code| self.process_code(code)
+    }
+
+    /// Returns the values of the table at `N` evenly spaced ADC codes
+    /// from the code of its first point to the code of its last, as
+    /// `(code, value)`, eg. for plotting the curve. No corrections from
+    /// the configuration are applied to the codes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let interpolator = AdcInterpolator::new(pin, config);
+    /// # let interpolator: AdcInterpolator<MockChan0, u16, 3> = interpolator;
+    ///
+    /// assert_eq!(
+    ///     interpolator.sample_curve::<5>(),
+    ///     [(409, 40), (613, 36), (818, 31), (1023, 21), (1228, 10)]
+    /// );
+    /// ```
+    pub fn sample_curve<const N: usize>(&self) -> [(Word, u32); N]
+    where
+        Word: Copy + Into<i64> + TryFrom<i64>,
+        V: Copy + Into<u32>,
+    {
+        let (first, last): (i64, i64) = (
+            self.table.codes[0].into(),
+            self.table.codes[LENGTH - 1].into(),
+        );
+        let (lowest, highest) = (self.table.x(0), self.table.x(LENGTH - 1));
+        let (lowest, highest) = (lowest.min(highest), lowest.max(highest));
+        let steps = N.saturating_sub(1).max(1) as i64;
+
+        core::array::from_fn(|index| {
+            let code = first + (last - first) * index as i64 / steps;
+            let adc_value = u32::try_from(code)
+                .map_or(lowest, |code| self.table.axis(code))
+                .clamp(lowest, highest);
+
+            // `code` lies between two of the table's codes, which are
+            // both `Word`s, so it always fits in one
+            let word = Word::try_from(code).unwrap_or(self.table.codes[0]);
+
+            (word, self.table.lookup(adc_value).unwrap_or_default())
+        })
+    }
+
+    /// Returns the ADC code of each point in the table, eg. for
+    /// programming a hardware comparator threshold aligned with the
+    /// calibration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let interpolator = AdcInterpolator::new(pin, config);
+    /// # let interpolator: AdcInterpolator<MockChan0, u16, 3> = interpolator;
+    ///
+    /// assert_eq!(interpolator.code_thresholds(), [409, 819, 1228]);
+    /// ```
+    pub fn code_thresholds(&self) -> [Word; LENGTH]
+    where
+        Word: Copy,
+    {
+        self.table.codes
+    }
+
+    /// Returns the slope of each segment of the table as `(rise, run)`,
+    /// where `rise` is the change in value and `run` is the change in
+    /// ADC code between the two points of the segment.
+    ///
+    /// The slopes are returned as fractions, since they are often
+    /// smaller than one value per code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let interpolator = AdcInterpolator::new(pin, config);
+    /// # let interpolator: AdcInterpolator<MockChan0, u16, 3> = interpolator;
+    ///
+    /// let mut slopes = interpolator.slopes();
+    ///
+    /// assert_eq!(slopes.next(), Some((-10, 410)));
+    /// assert_eq!(slopes.next(), Some((-20, 409)));
+    /// assert_eq!(slopes.next(), None);
+    /// ```
+    pub fn slopes(&self) -> impl Iterator<Item = (i64, u32)> + '_
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        (1..LENGTH).map(|index| {
+            let (x0, x1) = (self.table.code(index - 1), self.table.code(index));
+            let (y0, y1) = (self.table.node(index - 1).1, self.table.node(index).1);
+
+            (i64::from(y1.into()) - i64::from(y0.into()), x1 - x0)
+        })
+    }
+
+    /// Returns an estimate of the noise in the ADC codes read by any of
+    /// the interpolator's `read*` methods, as the standard deviation of
+    /// their exponentially weighted moving average, in ADC codes.
+    ///
+    /// Returns `0` until at least 8 codes have been read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: Vec<Transaction<u16>> = (0..100)
+    /// #     .map(|i| Transaction::read(0, if i % 2 == 0 { 600 } else { 640 }))
+    /// #     .collect();
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// assert_eq!(interpolator.noise_estimate(), 0);
+    ///
+    /// // The ADC alternates between codes 600 and 640
+    /// for _ in 0..100 {
+    ///     interpolator.read(&mut adc).unwrap();
+    /// }
+    ///
+    /// assert_eq!(interpolator.noise_estimate(), 20);
+    /// ```
+    pub fn noise_estimate(&self) -> u32 {
+        self.filter.noise.estimate()
+    }
+
+    /// Returns whether `target` was reached or passed between the last
+    /// two values within the range of the table read by any of the
+    /// interpolator's `read*` methods, eg. for detecting a setpoint.
+    ///
+    /// Returns `false` until two values have been read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 3] = [
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(0, 819),
+    /// #     Transaction::read(0, 1228),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// interpolator.read(&mut adc).unwrap();
+    /// interpolator.read(&mut adc).unwrap();
+    /// // The value fell from 35 to 30
+    /// assert!(interpolator.crossed(32));
+    ///
+    /// interpolator.read(&mut adc).unwrap();
+    /// // The value fell from 30 to 10
+    /// assert!(!interpolator.crossed(32));
+    /// ```
+    pub fn crossed(&self, target: u32) -> bool {
+        match (self.filter.before_latest, self.filter.latest) {
+            (Some(previous), Some(latest)) => {
+                (previous < target && latest >= target) || (previous > target && latest <= target)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the number of points in the table crossed between the
+    /// previous code and the latest one read from the ADC by any of the
+    /// interpolator's `read*` methods, eg. to detect that the input is changing too fast for the
+    /// sample rate to follow the curve between the points.
+    ///
+    /// As with [`crossed`](AdcInterpolator::crossed), a point is
+    /// crossed if the latest code reaches or passes its code. Returns
+    /// `0` until two codes have been read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 3] = [
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(0, 700),
+    /// #     Transaction::read(0, 1228),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// interpolator.read(&mut adc).unwrap();
+    /// interpolator.read(&mut adc).unwrap();
+    /// // Both readings are between the points at 100 mV and 200 mV
+    /// assert_eq!(interpolator.nodes_crossed(), 0);
+    ///
+    /// interpolator.read(&mut adc).unwrap();
+    /// // The reading passed the point at 200 mV, and reached the one at 300 mV
+    /// assert_eq!(interpolator.nodes_crossed(), 2);
+    /// ```
+    pub fn nodes_crossed(&self) -> usize
+    where
+        Word: Copy + Into<i64>,
+    {
+        let (Some(previous), Some(latest)) =
+            (self.filter.before_latest_code, self.filter.latest_code)
+        else {
+            return 0;
+        };
+        let (previous, latest): (i64, i64) = (previous.into(), latest.into());
+
+        self.table
+            .codes
+            .iter()
+            .map(|&code| code.into())
+            .filter(|&code| {
+                (previous < code && latest >= code) || (previous > code && latest <= code)
+            })
+            .count()
+    }
+
+    /// Returns the state kept between readings, which can be restored
+    /// later with [`restore_state`](AdcInterpolator::restore_state).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 2] = [
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(0, 1228),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut interpolator = AdcInterpolator::new(pin, config);
+    ///
+    /// interpolator.read_rate(&mut adc, 100).unwrap();
+    /// let state = interpolator.state();
+    ///
+    /// // Eg. after waking from sleep
+    /// let mut interpolator = AdcInterpolator::new(interpolator.free(), config);
+    /// interpolator.restore_state(state);
+    ///
+    /// assert_eq!(interpolator.read_rate(&mut adc, 100), Ok(Some(-250)));
+    /// ```
+    pub fn state(&self) -> FilterState<Word>
+    where
+        Word: Copy,
+    {
+        self.filter
+    }
+
+    /// Replaces the state kept between readings with `state`, which
+    /// was returned by [`state`](AdcInterpolator::state).
+    pub fn restore_state(&mut self, state: FilterState<Word>) {
+        self.filter = state;
+    }
+
+    /// Returns the smallest of the values of the points in the table,
+    /// which is the smallest value that can be interpolated linearly
+    /// by [`read`](AdcInterpolator::read).
+    pub fn min_value(&self) -> u32
+    where
+        V: Copy + Into<u32>,
+    {
+        self.table.value_range().0
+    }
+
+    /// Returns the largest of the values of the points in the table,
+    /// which is the largest value that can be interpolated linearly
+    /// by [`read`](AdcInterpolator::read).
+    pub fn max_value(&self) -> u32
+    where
+        V: Copy + Into<u32>,
+    {
+        self.table.value_range().1
+    }
+
+    /// Returns the smallest and largest of the values of the points in
+    /// the table, as `(min, max)`, from
+    /// [`min_value`](AdcInterpolator::min_value) and
+    /// [`max_value`](AdcInterpolator::max_value).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let interpolator = AdcInterpolator::new(pin, config);
+    /// # let interpolator: AdcInterpolator<MockChan0, u16, 3> = interpolator;
+    ///
+    /// assert_eq!(interpolator.value_range(), (10, 40));
+    /// ```
+    pub fn value_range(&self) -> (u32, u32)
+    where
+        V: Copy + Into<u32>,
+    {
+        self.table.value_range()
+    }
+
+    /// Returns whether the values of the points in the table are
+    /// monotonic, eg. before looking up the voltage for a value, which
+    /// is only possible if they are.
+    ///
+    /// Returns [`Ordering::Less`] if each value is less than or equal
+    /// to the next, [`Ordering::Greater`] if each is greater than or
+    /// equal to the next, [`Ordering::Equal`] if they are all the
+    /// same, or `None` if the values aren't monotonic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// use core::cmp::Ordering;
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let interpolator = AdcInterpolator::new(pin, config);
+    /// # let interpolator: AdcInterpolator<MockChan0, u16, 3> = interpolator;
+    ///
+    /// assert_eq!(interpolator.values_monotonic(), Some(Ordering::Greater));
+    /// ```
+    pub fn values_monotonic(&self) -> Option<Ordering>
+    where
+        V: Copy + PartialOrd,
+    {
+        self.table
+            .values
+            .windows(2)
+            .try_fold(Ordering::Equal, |ordering, pair| {
+                match (ordering, pair[0].partial_cmp(&pair[1])?) {
+                    (ordering, Ordering::Equal) => Some(ordering),
+                    (Ordering::Equal, next) => Some(next),
+                    (ordering, next) if ordering == next => Some(ordering),
+                    _ => None,
+                }
+            })
+    }
+
+    /// Returns the percentage of the ADC's full-scale range covered by
+    /// the table, from the code of its first point to the code of its
+    /// last.
+    ///
+    /// A low coverage may indicate an incorrect `max_voltage` or an
+    /// incomplete table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolator, Config};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let interpolator = AdcInterpolator::new(pin, config);
+    /// # let interpolator: AdcInterpolator<MockChan0, u16, 3> = interpolator;
+    ///
+    /// // 100 mV to 300 mV covers 20% of 0 mV to 1000 mV
+    /// assert_eq!(interpolator.coverage_percent(), 20);
+    /// ```
+    pub fn coverage_percent(&self) -> u8
+    where
+        Word: Copy + Into<i64>,
+    {
+        let span = self.table.code(0).abs_diff(self.table.code(LENGTH - 1));
+        let full_scale = self.table.config.full_scale_code().max(1);
+
+        (u64::from(span) * 100 / u64::from(full_scale)).min(100) as u8
+    }
+
+    /// Returns where `code` falls in the table, as
+    /// [`Table::locate`] does, recording the value that
+    /// [`read`](AdcInterpolator::read) would return for it.
+    fn locate(&mut self, code: Word) -> Located
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        let located = self.table.locate(code.into());
+        self.record(self.table.resolve(located, None));
+
+        located
+    }
+
+    /// Records `value`, returned by one of the `read*` methods, as the
+    /// latest value, and returns it.
+    fn record(&mut self, value: Option<u32>) -> Option<u32> {
+        if let Some(value) = value {
+            self.filter.before_latest = self.filter.latest.replace(value);
+        }
+
+        value
+    }
+
+    /// Counts `code`, read from the ADC, in `sample_count`, and updates
+    /// the noise estimate, the count of repeated codes and the latest
+    /// codes with it.
+    fn note(&mut self, code: Word)
+    where
+        Word: Copy + Into<i64>,
+    {
+        self.sample_count = self.sample_count.wrapping_add(1);
+        self.filter.noise.update(code.into());
+
+        let repeated = self
+            .filter
+            .latest_code
+            .is_some_and(|latest| latest.into() == code.into());
+        self.filter.repeats = if repeated {
+            self.filter.repeats.saturating_add(1)
+        } else {
+            0
+        };
+        self.filter.before_latest_code = self.filter.latest_code.replace(code);
+    }
+
+    /// Reads a code from the ADC, as every `read*` method does, and
+    /// [`note`](AdcInterpolator::note)s it.
+    fn sample<Adc, ADC>(&mut self, adc: &mut Adc) -> Result<Word, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64>,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+    {
+        let code = adc.read(&mut self.pin)?;
+        self.note(code);
+
+        Ok(code)
+    }
+
+    /// Reads a code from the ADC and throws it away, counting it in
+    /// `sample_count` but leaving the rest of the state kept between
+    /// readings unchanged.
+    fn discard<Adc, ADC>(&mut self, adc: &mut Adc) -> Result<(), Error<Adc, ADC, Word, Pin>>
+    where
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+    {
+        adc.read(&mut self.pin)?;
+        self.sample_count = self.sample_count.wrapping_add(1);
+
+        Ok(())
+    }
+
+    /// Like [`sample`](AdcInterpolator::sample), but reads from
+    /// `pin` instead of the interpolator's own pin.
+    fn sample_supply<Adc, ADC, SupplyPin>(
+        &mut self,
+        adc: &mut Adc,
+        pin: &mut SupplyPin,
+    ) -> Result<Word, Error<Adc, ADC, Word, SupplyPin>>
+    where
+        SupplyPin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, SupplyPin>,
+    {
+        let code = adc.read(pin)?;
+        self.sample_count = self.sample_count.wrapping_add(1);
+
+        Ok(code)
+    }
+}
+
+#[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "async")]
+    use crate::yield_now::tests::block_on;
+    use crate::{
+        config::{Config, OutOfRange},
+        interpolate::{Boundary, RoundingMode},
+    };
     use embedded_hal_mock::{
-        adc::{Mock, MockChan0, Transaction},
+        adc::{Mock, MockAdc, MockChan0, MockChan1, Transaction},
         common::Generic,
         MockError,
     };
     use std::io::ErrorKind;
 
-    fn table_positive() -> Config<3> {
-        Config {
+    fn table_positive() -> Config<3> {
+        Config {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [(100, 10), (200, 30), (300, 40)],
+            ..Default::default()
+        }
+    }
+
+    fn table_negative() -> Config<3> {
+        Config {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [(100, 40), (200, 30), (300, 10)],
+            ..Default::default()
+        }
+    }
+
+    fn table_peak() -> Config<3> {
+        Config {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [(100, 10), (200, 50), (300, 20)],
+            ..Default::default()
+        }
+    }
+
+    fn table_invalid() -> Config<3> {
+        Config {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [(300, 40), (200, 30), (100, 10)],
+            ..Default::default()
+        }
+    }
+
+    fn interpolator<const LENGTH: usize>(
+        config: Config<LENGTH>,
+    ) -> AdcInterpolator<MockChan0, u16, LENGTH> {
+        let pin = MockChan0 {};
+        AdcInterpolator::new(pin, config)
+    }
+
+    fn adc(expectations: &[Transaction<u16>]) -> Generic<Transaction<u16>> {
+        Mock::new(expectations)
+    }
+
+    fn assert_read_ok<const LENGTH: usize>(
+        config: Config<LENGTH>,
+        value: u16,
+        expected: Option<u32>,
+    ) {
+        let mut interpolator = interpolator(config);
+        let expectations = [Transaction::read(0, value)];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read(&mut adc), Ok(expected))
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_if_unsorted_tabled() {
+        interpolator(table_invalid());
+    }
+
+    #[test]
+    fn unsorted_table_reads_out_of_range() {
+        let config = table_invalid();
+        let mut interpolator: AdcInterpolator<_, u16, 3> =
+            AdcInterpolator::from_codes(MockChan0 {}, config, config.codes().unwrap());
+
+        let expectations = [
+            Transaction::read(0, 614),
+            Transaction::read(0, 614),
+            Transaction::read(0, 614),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read(&mut adc), Ok(None));
+        assert_eq!(interpolator.read_value(&mut adc), Ok(None));
+
+        interpolator.set_point(0, 100, 40).unwrap();
+        interpolator.set_point(2, 300, 10).unwrap();
+        assert_eq!(interpolator.read(&mut adc), Ok(Some(35)));
+    }
+
+    #[test]
+    fn with_reference() {
+        let template = interpolator(table_positive());
+
+        let interpolator = template.with_reference(MockChan1 {}, 2000).unwrap();
+        assert_eq!(interpolator.point(1), Some((200, 30)));
+        assert_eq!(interpolator.process_code(409), Some(30));
+        assert_eq!(interpolator.process_code(204), Some(10));
+        assert_eq!(interpolator.process_code(203), None);
+
+        let result = template.with_reference(MockChan1 {}, 1);
+        assert_eq!(
+            result.unwrap_err(),
+            ConfigError::CodeOutOfRange { index: 0 }
+        );
+    }
+
+    #[test]
+    fn table_bytes() {
+        let interpolator = interpolator(table_positive());
+
+        let mut bytes = [0xff; 25];
+        assert_eq!(interpolator.table_bytes(&mut bytes[..23]), 0);
+        assert_eq!(interpolator.table_bytes(&mut bytes), 24);
+        assert_eq!(bytes[24], 0xff);
+
+        let config = Config {
+            voltage_to_values: [(0, 0); 3],
+            ..table_positive()
+        };
+        let loaded: AdcInterpolator<_, u16, 3> =
+            AdcInterpolator::from_table_bytes(MockChan0 {}, config, &bytes).unwrap();
+
+        assert_eq!(loaded.table.codes, interpolator.table.codes);
+        assert_eq!(loaded.point(0), Some((99, 10)));
+        assert_eq!(loaded.process_code(614), Some(20));
+    }
+
+    #[test]
+    fn from_table_bytes_errors() {
+        let config = Config {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [(0, 0); 2],
+            ..Default::default()
+        };
+
+        let result: Result<AdcInterpolator<_, u16, 2>, _> =
+            AdcInterpolator::from_table_bytes(MockChan0 {}, config, &[0; 15]);
+        assert_eq!(result.unwrap_err(), ConfigError::TableTooShort);
+
+        let bytes = [2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0];
+        let result: Result<AdcInterpolator<_, u16, 2>, _> =
+            AdcInterpolator::from_table_bytes(MockChan0 {}, config, &bytes);
+        assert_eq!(result.unwrap_err(), ConfigError::NotAscending { index: 1 });
+
+        let bytes = [1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0];
+        let result: Result<AdcInterpolator<_, u16, 2>, _> =
+            AdcInterpolator::from_table_bytes(MockChan0 {}, config, &bytes);
+        assert_eq!(result.unwrap_err(), ConfigError::NotAscending { index: 1 });
+
+        let bytes = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0];
+        let result: Result<AdcInterpolator<_, u8, 2>, _> =
+            AdcInterpolator::from_table_bytes(MockChan0 {}, config, &bytes);
+        assert_eq!(
+            result.unwrap_err(),
+            ConfigError::CodeOutOfRange { index: 1 }
+        );
+
+        // above the full-scale code of 4095, though it fits in a `u16`
+        let bytes = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0x10, 0, 0, 0, 0, 0, 0];
+        let result: Result<AdcInterpolator<_, u16, 2>, _> =
+            AdcInterpolator::from_table_bytes(MockChan0 {}, config, &bytes);
+        assert_eq!(
+            result.unwrap_err(),
+            ConfigError::CodeOutOfRange { index: 1 }
+        );
+    }
+
+    #[test]
+    fn from_table_bytes_narrow_values() {
+        let config: ValueConfig<2, u16> = ValueConfig {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [(0, 0); 2],
+            ..Default::default()
+        };
+        let interpolator: AdcInterpolator<MockChan0, u16, 2, u16> = AdcInterpolator::new(
+            MockChan0 {},
+            ValueConfig {
+                voltage_to_values: [(100, 10), (300, 60_000)],
+                ..config
+            },
+        );
+        let mut bytes = [0; 16];
+        assert_eq!(interpolator.table_bytes(&mut bytes), 16);
+
+        let loaded: AdcInterpolator<MockChan0, u16, 2, u16> =
+            AdcInterpolator::from_table_bytes(MockChan0 {}, config, &bytes).unwrap();
+        assert_eq!(loaded.process_code(409), Some(10));
+        assert_eq!(loaded.process_code(1228), Some(60_000));
+
+        // a value above `u16::MAX`
+        bytes[14] = 1;
+        let result: Result<AdcInterpolator<MockChan0, u16, 2, u16>, _> =
+            AdcInterpolator::from_table_bytes(MockChan0 {}, config, &bytes);
+        assert_eq!(
+            result.unwrap_err(),
+            ConfigError::ValueOutOfRange { index: 1 }
+        );
+    }
+
+    #[test]
+    fn from_table_bytes_repeated_codes() {
+        let config = Config {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [(0, 0); 3],
+            ..Default::default()
+        };
+
+        let bytes = [
+            0x99, 0x01, 0, 0, 40, 0, 0, 0, // code 409 -> 40
+            0x99, 0x01, 0, 0, 30, 0, 0, 0, // code 409 -> 30
+            0xcc, 0x04, 0, 0, 10, 0, 0, 0, // code 1228 -> 10
+        ];
+        let result: Result<AdcInterpolator<_, u16, 3>, _> =
+            AdcInterpolator::from_table_bytes(MockChan0 {}, config, &bytes);
+        assert_eq!(result.unwrap_err(), ConfigError::NotAscending { index: 1 });
+
+        // Codes 1 and 2 are both 0 mV, but are still different codes
+        let bytes = [
+            1, 0, 0, 0, 40, 0, 0, 0, // code 1 -> 40
+            2, 0, 0, 0, 30, 0, 0, 0, // code 2 -> 30
+            0xcc, 0x04, 0, 0, 10, 0, 0, 0, // code 1228 -> 10
+        ];
+        let interpolator: AdcInterpolator<_, u16, 3> =
+            AdcInterpolator::from_table_bytes(MockChan0 {}, config, &bytes).unwrap();
+        assert_eq!(interpolator.process_code(2), Some(30));
+    }
+
+    #[test]
+    fn try_new() {
+        let interpolator: Result<AdcInterpolator<MockChan0, u16, 3>, _> =
+            AdcInterpolator::try_new(MockChan0 {}, table_positive());
+        assert!(interpolator.is_ok());
+
+        let interpolator: Result<AdcInterpolator<MockChan0, u16, 3>, _> =
+            AdcInterpolator::try_new(MockChan0 {}, table_invalid());
+        assert_eq!(
+            interpolator.unwrap_err(),
+            ConfigError::NotAscending { index: 1 }
+        );
+    }
+
+    #[test]
+    fn try_new_rejects_panicking_configs() {
+        let duplicate = Config {
+            voltage_to_values: [(100, 40), (100, 30), (300, 10)],
+            ..table_negative()
+        };
+        let interpolator: Result<AdcInterpolator<MockChan0, u16, 3>, _> =
+            AdcInterpolator::try_new(MockChan0 {}, duplicate);
+        assert_eq!(
+            interpolator.unwrap_err(),
+            ConfigError::NotAscending { index: 1 }
+        );
+
+        let precision = Config {
+            precision: 32,
+            ..table_negative()
+        };
+        let interpolator: Result<AdcInterpolator<MockChan0, u32, 3>, _> =
+            AdcInterpolator::try_new(MockChan0 {}, precision);
+        assert_eq!(
+            interpolator.unwrap_err(),
+            ConfigError::InvalidPrecision { precision: 32 }
+        );
+    }
+
+    #[test]
+    fn zero_width_segment() {
+        let interpolator = interpolator(Config {
+            voltage_to_values: [(100, 40), (100, 30), (300, 10)],
+            ..table_negative()
+        });
+
+        assert_eq!(interpolator.process_code(409), Some(30));
+        assert_eq!(interpolator.process_code(818), Some(21));
+    }
+
+    #[test]
+    fn from_table() {
+        let table: Table<u16, 3> = table_positive().into();
+        let mut first = AdcInterpolator::from_table(MockChan0 {}, table);
+        let mut second = AdcInterpolator::from_table(MockChan1 {}, table);
+
+        let expectations = [Transaction::read(0, 409), Transaction::read(1, 1228)];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(first.read(&mut adc), Ok(Some(10)));
+        assert_eq!(second.read(&mut adc), Ok(Some(40)));
+        assert_eq!(first.code_thresholds(), [409, 819, 1228]);
+        assert_eq!(first.sample_count(), 1);
+        adc.done();
+    }
+
+    #[test]
+    fn try_new_precision_exceeds_word() {
+        let config = Config {
+            precision: 16,
+            ..table_positive()
+        };
+        let interpolator: Result<AdcInterpolator<MockChan0, u8, 3>, _> =
+            AdcInterpolator::try_new(MockChan0 {}, config);
+
+        assert_eq!(
+            interpolator.unwrap_err(),
+            ConfigError::PrecisionExceedsWord { precision: 16 }
+        );
+    }
+
+    #[test]
+    fn try_new_code_out_of_range() {
+        let config = Config {
+            max_voltage: 1000,
+            precision: 8,
+            voltage_to_values: [(0, 0), (999, 5), (1000, 10)],
+            ..Default::default()
+        };
+        let interpolator: Result<AdcInterpolator<MockChan0, u8, 3>, _> =
+            AdcInterpolator::try_new(MockChan0 {}, config);
+
+        assert_eq!(
+            interpolator.unwrap_err(),
+            ConfigError::CodeOutOfRange { index: 2 }
+        );
+    }
+
+    #[test]
+    fn try_new_points_too_close() {
+        let config = Config {
+            min_code_spacing: 5,
+            voltage_to_values: [(100, 40), (200, 30), (201, 10)],
+            ..table_negative()
+        };
+        let interpolator: Result<AdcInterpolator<MockChan0, u16, 3>, _> =
+            AdcInterpolator::try_new(MockChan0 {}, config);
+
+        assert_eq!(
+            interpolator.unwrap_err(),
+            ConfigError::PointsTooClose { index: 2 }
+        );
+    }
+
+    #[test]
+    fn matching_exact_values() {
+        assert_read_ok(table_negative(), 409, Some(40));
+        assert_read_ok(table_negative(), 819, Some(30));
+        assert_read_ok(table_negative(), 1228, Some(10));
+    }
+
+    #[test]
+    fn interpolates() {
+        assert_read_ok(table_negative(), 502, Some(38));
+        assert_read_ok(table_negative(), 614, Some(35));
+        assert_read_ok(table_negative(), 1023, Some(21));
+    }
+
+    #[test]
+    fn outside_range() {
+        assert_read_ok(table_negative(), 0, None);
+        assert_read_ok(table_negative(), 408, None);
+        assert_read_ok(table_negative(), 1229, None);
+        assert_read_ok(table_negative(), 10000, None);
+    }
+
+    #[test]
+    fn error() {
+        let mut adc =
+            adc(&[Transaction::read(0, 0).with_error(MockError::Io(ErrorKind::InvalidData))]);
+        assert!(interpolator(table_positive()).read(&mut adc).is_err());
+    }
+
+    #[test]
+    fn read_n() {
+        let mut interpolator = interpolator(table_negative());
+        let expectations = [
+            Transaction::read(0, 409),
+            Transaction::read(0, 614),
+            Transaction::read(0, 1229),
+            Transaction::read(0, 1228),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(
+            interpolator.read_n(&mut adc),
+            Ok([Some(40), Some(35), None, Some(10)])
+        );
+    }
+
+    #[test]
+    fn read_n_error() {
+        let mut interpolator = interpolator(table_negative());
+        let expectations = [
+            Transaction::read(0, 409),
+            Transaction::read(0, 0).with_error(MockError::Io(ErrorKind::InvalidData)),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert!(interpolator.read_n::<_, _, 2>(&mut adc).is_err());
+    }
+
+    #[test]
+    fn read_detailed() {
+        let mut interpolator = interpolator(table_negative());
+        let expectations = [
+            Transaction::read(0, 614),
+            Transaction::read(0, 1),
+            Transaction::read(0, 408),
+            Transaction::read(0, 1229),
+            Transaction::read(0, 4094),
+            Transaction::read(0, 0),
+            Transaction::read(0, 4095),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(
+            interpolator.read_detailed(&mut adc),
+            Ok(Reading::InRange(35))
+        );
+        assert_eq!(interpolator.read_detailed(&mut adc), Ok(Reading::Below));
+        assert_eq!(interpolator.read_detailed(&mut adc), Ok(Reading::Below));
+        assert_eq!(interpolator.read_detailed(&mut adc), Ok(Reading::Above));
+        assert_eq!(interpolator.read_detailed(&mut adc), Ok(Reading::Above));
+        assert_eq!(interpolator.read_detailed(&mut adc), Ok(Reading::Saturated));
+        assert_eq!(interpolator.read_detailed(&mut adc), Ok(Reading::Saturated));
+    }
+
+    #[test]
+    fn inl_correction() {
+        let config = Config {
+            inl_correction: &[(409, 100), (1228, -100)],
+            ..table_negative()
+        };
+        let mut interpolator = interpolator(config);
+        let expectations = [
+            Transaction::read(0, 309),
+            Transaction::read(0, 409),
+            Transaction::read(0, 1328),
+            Transaction::read(0, 4095),
+            Transaction::read(0, 1638),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read(&mut adc), Ok(Some(40)));
+        assert_eq!(interpolator.read(&mut adc), Ok(Some(38)));
+        assert_eq!(interpolator.read(&mut adc), Ok(Some(10)));
+        assert_eq!(interpolator.read_detailed(&mut adc), Ok(Reading::Saturated));
+        assert_eq!(interpolator.read_voltage(&mut adc), Ok(375));
+    }
+
+    #[test]
+    fn read_voltage() {
+        let mut interpolator = interpolator(table_negative());
+        let expectations = [
+            Transaction::read(0, 0),
+            Transaction::read(0, 614),
+            Transaction::read(0, 10000),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_voltage(&mut adc), Ok(0));
+        assert_eq!(interpolator.read_voltage(&mut adc), Ok(149));
+        assert_eq!(interpolator.read_voltage(&mut adc), Ok(2441));
+    }
+
+    #[test]
+    fn set_precision() {
+        let mut interpolator = interpolator(table_negative());
+
+        assert_eq!(interpolator.set_precision(10), Ok(()));
+        assert_eq!(interpolator.process_code(153), Some(35));
+        assert_eq!(interpolator.process_code(307), Some(10));
+        assert_eq!(interpolator.process_code(308), None);
+        assert_eq!(interpolator.code_thresholds(), [102, 204, 307]);
+
+        assert_eq!(
+            interpolator.set_precision(32),
+            Err(ConfigError::InvalidPrecision { precision: 32 })
+        );
+
+        let pin = MockChan0 {};
+        let mut narrow: AdcInterpolator<_, u8, 3> =
+            AdcInterpolator::new(pin, table_negative().with_precision(8));
+
+        assert_eq!(
+            narrow.set_precision(12),
+            Err(ConfigError::PrecisionExceedsWord { precision: 12 })
+        );
+        assert_eq!(narrow.code_thresholds(), [25, 51, 76]);
+    }
+
+    #[test]
+    fn read_with_mode() {
+        let config = Config {
+            segment_interpolation: &[Interpolation::Floor],
+            ..table_positive()
+        };
+        let mut interpolator = interpolator(config);
+        let expectations = [
+            Transaction::read(0, 700),
+            Transaction::read(0, 700),
+            Transaction::read(0, 1000),
+            Transaction::read(0, 1000),
+            Transaction::read(0, 1000),
+            Transaction::read(0, 2000),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read(&mut adc), Ok(Some(10)));
+        assert_eq!(
+            interpolator.read_with_mode(&mut adc, Interpolation::Linear),
+            Ok(Some(24))
+        );
+        assert_eq!(interpolator.read(&mut adc), Ok(Some(34)));
+        assert_eq!(
+            interpolator.read_with_mode(&mut adc, Interpolation::Nearest),
+            Ok(Some(30))
+        );
+        assert_eq!(
+            interpolator.read_with_mode(&mut adc, Interpolation::Floor),
+            Ok(Some(30))
+        );
+        assert_eq!(
+            interpolator.read_with_mode(&mut adc, Interpolation::Nearest),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn read_full() {
+        let mut interpolator = interpolator(table_negative());
+        let expectations = [
+            Transaction::read(0, 1126),
+            Transaction::read(0, 10000),
+            Transaction::read(0, 0).with_error(MockError::Io(ErrorKind::InvalidData)),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(
+            interpolator.read_full(&mut adc),
+            Ok(FullReading {
+                code: 1126,
+                voltage_mv: 274,
+                value: Some(15),
+            })
+        );
+        assert_eq!(
+            interpolator.read_full(&mut adc),
+            Ok(FullReading {
+                code: 10000,
+                voltage_mv: 2441,
+                value: None,
+            })
+        );
+        assert!(interpolator.read_full(&mut adc).is_err());
+        assert_eq!(interpolator.sample_count(), 2);
+    }
+
+    #[test]
+    fn read_node() {
+        let mut interpolator = interpolator(table_positive());
+        let expectations = [
+            Transaction::read(0, 409),
+            Transaction::read(0, 410),
+            Transaction::read(0, 1228),
+            Transaction::read(0, 1229),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_node(&mut adc), Ok(Some((10, true))));
+        assert_eq!(interpolator.read_node(&mut adc), Ok(Some((10, false))));
+        assert_eq!(interpolator.read_node(&mut adc), Ok(Some((40, true))));
+        assert_eq!(interpolator.read_node(&mut adc), Ok(None));
+    }
+
+    #[test]
+    fn read_code_hysteresis() {
+        let mut interpolator = interpolator(table_negative());
+
+        let expectations = [
+            Transaction::read(0, 614),
+            Transaction::read(0, 655),
+            Transaction::read(0, 660),
+            Transaction::read(0, 620),
+            Transaction::read(0, 0),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(
+            interpolator.read_code_hysteresis(&mut adc, 45),
+            Ok(Some(35))
+        );
+        assert_eq!(
+            interpolator.read_code_hysteresis(&mut adc, 45),
+            Ok(Some(35))
+        );
+        assert_eq!(
+            interpolator.read_code_hysteresis(&mut adc, 45),
+            Ok(Some(34))
+        );
+        assert_eq!(
+            interpolator.read_code_hysteresis(&mut adc, 45),
+            Ok(Some(34))
+        );
+        assert_eq!(interpolator.read_code_hysteresis(&mut adc, 45), Ok(None));
+    }
+
+    #[test]
+    fn read_code_hysteresis_reset_by_set_point() {
+        let mut interpolator = interpolator(table_negative());
+
+        let expectations = [Transaction::read(0, 655), Transaction::read(0, 655)];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(
+            interpolator.read_code_hysteresis(&mut adc, 45),
+            Ok(Some(34))
+        );
+        interpolator.set_point(0, 100, 50).unwrap();
+        assert_eq!(
+            interpolator.read_code_hysteresis(&mut adc, 45),
+            Ok(Some(38))
+        );
+    }
+
+    #[test]
+    fn read_checked_delta() {
+        let mut interpolator = interpolator(table_negative());
+
+        let expectations = [
+            Transaction::read(0, 614),
+            Transaction::read(0, 0),
+            Transaction::read(0, 1228),
+            Transaction::read(0, 1200),
+            Transaction::read(0, 0).with_error(MockError::Io(ErrorKind::InvalidData)),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_checked_delta(&mut adc, 10), Ok(Some(35)));
+        assert_eq!(interpolator.read_checked_delta(&mut adc, 10), Ok(None));
+        assert_eq!(
+            interpolator.read_checked_delta(&mut adc, 10),
+            Err(DeltaError::Exceeded {
+                previous: 35,
+                current: 10
+            })
+        );
+        assert_eq!(interpolator.read_checked_delta(&mut adc, 10), Ok(Some(12)));
+        assert!(matches!(
+            interpolator.read_checked_delta(&mut adc, 10),
+            Err(DeltaError::Adc(_))
+        ));
+    }
+
+    #[test]
+    fn read_required() {
+        let mut interpolator = interpolator(table_negative());
+
+        let expectations = [
+            Transaction::read(0, 614),
+            Transaction::read(0, 0),
+            Transaction::read(0, 0).with_error(MockError::Io(ErrorKind::InvalidData)),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_required(&mut adc), Ok(35));
+        assert_eq!(
+            interpolator.read_required(&mut adc),
+            Err(ReadError::OutOfRange)
+        );
+        assert!(matches!(
+            interpolator.read_required(&mut adc),
+            Err(ReadError::Adc(_))
+        ));
+    }
+
+    #[test]
+    fn read_or_report() {
+        let config = Config {
+            plausible_range: Some((100, 4000)),
+            ..table_positive()
+        };
+        let mut interpolator = interpolator(config);
+        let expectations = [
+            Transaction::read(0, 819),
+            Transaction::read(0, 200),
+            Transaction::read(0, 1300),
+            Transaction::read(0, 4001),
+            Transaction::read(0, 0).with_error(MockError::Io(ErrorKind::InvalidData)),
+        ];
+        let mut adc = adc(&expectations);
+        let diagnostic = |code, reading| Diagnostic {
+            code,
+            table_range: (409, 1228),
+            reading,
+        };
+
+        assert_eq!(interpolator.read_or_report(&mut adc), Ok(Ok(30)));
+        assert_eq!(
+            interpolator.read_or_report(&mut adc),
+            Ok(Err(diagnostic(200, Reading::Below)))
+        );
+        assert_eq!(
+            interpolator.read_or_report(&mut adc),
+            Ok(Err(diagnostic(1300, Reading::Above)))
+        );
+        assert_eq!(
+            interpolator.read_or_report(&mut adc),
+            Ok(Err(diagnostic(4001, Reading::Implausible)))
+        );
+        assert!(interpolator.read_or_report(&mut adc).is_err());
+    }
+
+    #[test]
+    fn rail_is_error() {
+        let config = table_negative().with_out_of_range(OutOfRange::Clamp);
+        assert_read_ok(config, 0, Some(40));
+        assert_read_ok(config, 4095, Some(10));
+
+        let config = Config {
+            rail_is_error: true,
+            ..config
+        };
+        assert_read_ok(config, 0, None);
+        assert_read_ok(config, 1, Some(40));
+        assert_read_ok(config, 4094, Some(10));
+        assert_read_ok(config, 4095, None);
+
+        let mut interpolator = interpolator(config);
+        let expectations = [
+            Transaction::read(0, 0),
+            Transaction::read(0, 4095),
+            Transaction::read(0, 614),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_required(&mut adc), Err(ReadError::Railed));
+        assert_eq!(interpolator.read_required(&mut adc), Err(ReadError::Railed));
+        assert_eq!(interpolator.read_required(&mut adc), Ok(35));
+    }
+
+    /// A configuration which clamps readings above the table, tolerates
+    /// a margin of 16 codes, and treats railed codes as faults.
+    fn table_clamped() -> Config<3> {
+        Config {
+            out_of_range_high: OutOfRange::Clamp,
+            clamp_margin: 16,
+            rail_is_error: true,
+            ..table_positive()
+        }
+    }
+
+    #[test]
+    fn read_ratio_out_of_range() {
+        let mut interpolator = interpolator(table_clamped());
+        let expectations = [
+            Transaction::read(0, 2000),
+            Transaction::read(0, 400),
+            Transaction::read(0, 300),
+            Transaction::read(0, 4095),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_ratio(&mut adc), Ok(Some((40, 1))));
+        assert_eq!(interpolator.read_ratio(&mut adc), Ok(Some((10, 1))));
+        assert_eq!(interpolator.read_ratio(&mut adc), Ok(None));
+        assert_eq!(interpolator.read_ratio(&mut adc), Ok(None));
+    }
+
+    #[test]
+    fn read_q16_out_of_range() {
+        let mut interpolator = interpolator(table_clamped());
+        let expectations = [Transaction::read(0, 2000), Transaction::read(0, 4095)];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_q16(&mut adc), Ok(Some(40 << 16)));
+        assert_eq!(interpolator.read_q16(&mut adc), Ok(None));
+    }
+
+    #[test]
+    fn read_both_out_of_range() {
+        let mut interpolator = interpolator(table_clamped());
+        let expectations = [Transaction::read(0, 2000), Transaction::read(0, 300)];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_both(&mut adc), Ok(Some((40, 40))));
+        assert_eq!(interpolator.read_both(&mut adc), Ok(None));
+    }
+
+    #[test]
+    fn read_with_confidence_out_of_range() {
+        let mut interpolator = interpolator(table_clamped());
+        let expectations = [Transaction::read(0, 2000), Transaction::read(0, 4095)];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(
+            interpolator.read_with_confidence(&mut adc),
+            Ok(Some((40, 0)))
+        );
+        assert_eq!(interpolator.read_with_confidence(&mut adc), Ok(None));
+    }
+
+    #[test]
+    fn read_with_uncertainty_out_of_range() {
+        let mut interpolator = interpolator(table_clamped());
+        let expectations = [Transaction::read(0, 2000), Transaction::read(0, 0)];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(
+            interpolator.read_with_uncertainty(&mut adc, 41),
+            Ok(Some((40, 0)))
+        );
+        assert_eq!(interpolator.read_with_uncertainty(&mut adc, 41), Ok(None));
+    }
+
+    #[test]
+    fn read_code_hysteresis_out_of_range() {
+        let mut interpolator = interpolator(table_clamped());
+        let expectations = [
+            Transaction::read(0, 2000),
+            Transaction::read(0, 1240),
+            Transaction::read(0, 4095),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_code_hysteresis(&mut adc, 0), Ok(Some(40)));
+        assert_eq!(interpolator.read_code_hysteresis(&mut adc, 0), Ok(Some(40)));
+        assert_eq!(interpolator.read_code_hysteresis(&mut adc, 0), Ok(None));
+    }
+
+    #[test]
+    fn state_shared_by_read_variants() {
+        let config = Config {
+            stuck_after: Some(5),
+            ..table_positive()
+        };
+        let mut interpolator = interpolator(config);
+        let expectations = [
+            Transaction::read(0, 600),
+            Transaction::read(0, 640),
+            Transaction::read(0, 1000),
+            Transaction::read(0, 1000),
+            Transaction::read(0, 1000),
+            Transaction::read(0, 1000),
+            Transaction::read(0, 1000),
+            Transaction::read(0, 409),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_averaged(&mut adc, 2), Ok(Some(20)));
+        assert_eq!(
+            interpolator.read_with_mode(&mut adc, Interpolation::Floor),
+            Ok(Some(30))
+        );
+        assert!(interpolator.crossed(25));
+        assert_eq!(interpolator.nodes_crossed(), 1);
+
+        assert_eq!(
+            interpolator.read_trimmed::<_, _, 3>(&mut adc, 1),
+            Ok(Some(34))
+        );
+        assert!(interpolator.crossed(32));
+        assert_eq!(interpolator.nodes_crossed(), 0);
+        assert_eq!(interpolator.noise_estimate(), 0);
+
+        // the code has repeated for the reads above, as well as this one
+        assert_eq!(interpolator.read_required(&mut adc), Err(ReadError::Stuck));
+        assert_eq!(
+            interpolator.read_trend(&mut adc),
+            Ok((Some(10), Trend::Falling))
+        );
+        assert_ne!(interpolator.noise_estimate(), 0);
+    }
+
+    #[test]
+    fn read_with_discard_state() {
+        let mut interpolator = interpolator(table_positive());
+        let expectations = [
+            Transaction::read(0, 614),
+            Transaction::read(0, 2000),
+            Transaction::read(0, 700),
+            Transaction::read(0, 1228),
+        ];
+        let mut adc = adc(&expectations);
+
+        interpolator.read(&mut adc).unwrap();
+        interpolator.read_with_discard(&mut adc, 1, 1).unwrap();
+        // the discarded code of 2000 would have crossed two points
+        assert_eq!(interpolator.nodes_crossed(), 0);
+        assert_eq!(interpolator.sample_count(), 3);
+
+        assert_eq!(interpolator.read_ratio(&mut adc), Ok(Some((16360, 409))));
+        assert!(interpolator.crossed(38));
+        assert_eq!(interpolator.nodes_crossed(), 2);
+    }
+
+    #[test]
+    fn stuck_after() {
+        let config = Config {
+            stuck_after: Some(3),
+            ..table_negative()
+        };
+        let mut interpolator = interpolator(config);
+        let expectations = [
+            Transaction::read(0, 614),
+            Transaction::read(0, 614),
+            Transaction::read(0, 614),
+            Transaction::read(0, 614),
+            Transaction::read(0, 615),
+            Transaction::read(0, 615),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_required(&mut adc), Ok(35));
+        assert_eq!(interpolator.read_required(&mut adc), Ok(35));
+        assert_eq!(interpolator.read_required(&mut adc), Err(ReadError::Stuck));
+        assert_eq!(interpolator.read_required(&mut adc), Err(ReadError::Stuck));
+        assert_eq!(interpolator.read_required(&mut adc), Ok(35));
+        assert_eq!(interpolator.read_required(&mut adc), Ok(35));
+    }
+
+    #[test]
+    fn stuck_after_minimum() {
+        let config = Config {
+            stuck_after: Some(2),
+            ..table_negative()
+        };
+        let mut interpolator = interpolator(config);
+        let expectations = [Transaction::read(0, 614), Transaction::read(0, 614)];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_required(&mut adc), Ok(35));
+        assert_eq!(interpolator.read_required(&mut adc), Err(ReadError::Stuck));
+
+        let config = Config {
+            stuck_after: Some(1),
+            ..table_negative()
+        };
+        let result: Result<AdcInterpolator<_, u16, 3>, _> =
+            AdcInterpolator::try_new(MockChan0 {}, config);
+        assert_eq!(
+            result.unwrap_err(),
+            ConfigError::InvalidStuckAfter { stuck_after: 1 }
+        );
+    }
+
+    #[test]
+    fn stuck_after_disabled() {
+        let mut interpolator = interpolator(table_negative());
+        let expectations: Vec<_> = (0..10).map(|_| Transaction::read(0, 614)).collect();
+        let mut adc = adc(&expectations);
+
+        for _ in 0..10 {
+            assert_eq!(interpolator.read_required(&mut adc), Ok(35));
+        }
+    }
+
+    #[test]
+    fn read_rate() {
+        let mut interpolator = interpolator(table_positive());
+
+        let expectations = [
+            Transaction::read(0, 409),
+            Transaction::read(0, 0),
+            Transaction::read(0, 819),
+            Transaction::read(0, 1228),
+            Transaction::read(0, 409),
+            Transaction::read(0, 409),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_rate(&mut adc, 10), Ok(None));
+        assert_eq!(interpolator.read_rate(&mut adc, 10), Ok(None));
+        assert_eq!(interpolator.read_rate(&mut adc, 10), Ok(Some(2000)));
+        assert_eq!(interpolator.read_rate(&mut adc, 3), Ok(Some(3333)));
+        assert_eq!(interpolator.read_rate(&mut adc, 0), Ok(None));
+        assert_eq!(interpolator.read_rate(&mut adc, 1), Ok(Some(0)));
+    }
+
+    #[test]
+    fn read_rate_does_not_share_previous_value() {
+        let mut interpolator = interpolator(table_positive());
+
+        let expectations = [Transaction::read(0, 409), Transaction::read(0, 819)];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_checked_delta(&mut adc, 100), Ok(Some(10)));
+        assert_eq!(interpolator.read_rate(&mut adc, 10), Ok(None));
+    }
+
+    struct OwnedAdc {
+        codes: Vec<nb::Result<u16, ()>>,
+    }
+
+    impl OneShotOwned<MockAdc, u16, MockChan0> for OwnedAdc {
+        type Error = ();
+
+        fn read(&mut self, pin: MockChan0) -> (MockChan0, nb::Result<u16, ()>) {
+            (pin, self.codes.remove(0))
+        }
+    }
+
+    #[test]
+    fn read_owned() {
+        let interpolator = interpolator(table_positive());
+        let mut adc = OwnedAdc {
+            codes: vec![Ok(614), Ok(1229), Err(nb::Error::WouldBlock)],
+        };
+
+        let (interpolator, value) = interpolator.read_owned(&mut adc);
+        assert_eq!(value, Ok(Some(20)));
+
+        let (interpolator, value) = interpolator.read_owned(&mut adc);
+        assert_eq!(value, Ok(None));
+
+        let (interpolator, value) = interpolator.read_owned(&mut adc);
+        assert_eq!(value, Err(nb::Error::WouldBlock));
+        assert_eq!(interpolator.sample_count(), 2);
+    }
+
+    #[test]
+    fn read_window_sum() {
+        let mut interpolator = interpolator(table_positive());
+        let mut window = WindowSum::<3>::new();
+
+        let expectations = [
+            Transaction::read(0, 409),
+            Transaction::read(0, 0),
+            Transaction::read(0, 614),
+            Transaction::read(0, 819),
+            Transaction::read(0, 1228),
+        ];
+        let mut adc = adc(&expectations);
+
+        let mut read = || interpolator.read_window_sum(&mut adc, &mut window);
+        assert_eq!(read(), Ok(Some(10)));
+        assert_eq!(read(), Ok(None));
+        assert_eq!(read(), Ok(Some(30)));
+        assert_eq!(read(), Ok(Some(60)));
+        assert_eq!(read(), Ok(Some(90)));
+        assert_eq!(window.len(), 3);
+    }
+
+    #[test]
+    fn read_timed() {
+        let mut interpolator = interpolator(table_positive());
+        let mut calls = 0;
+
+        let expectations = [
+            Transaction::read(0, 0).with_error(MockError::Io(ErrorKind::InvalidData)),
+            Transaction::read(0, 0),
+            Transaction::read(0, 614),
+        ];
+        let mut adc = adc(&expectations);
+
+        let mut now = || {
+            calls += 1;
+            calls * 100
+        };
+        assert!(interpolator.read_timed(&mut adc, &mut now).is_err());
+        assert_eq!(interpolator.read_timed(&mut adc, &mut now), Ok((100, None)));
+        assert_eq!(
+            interpolator.read_timed(&mut adc, &mut now),
+            Ok((200, Some(20)))
+        );
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn read_ratio() {
+        let mut interpolator = interpolator(table_positive());
+
+        let expectations = [
+            Transaction::read(0, 409),
+            Transaction::read(0, 1000),
+            Transaction::read(0, 1228),
+            Transaction::read(0, 1229),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_ratio(&mut adc), Ok(Some((4100, 410))));
+        assert_eq!(interpolator.read_ratio(&mut adc), Ok(Some((14080, 409))));
+        assert_eq!(interpolator.read_ratio(&mut adc), Ok(Some((16360, 409))));
+        assert_eq!(interpolator.read_ratio(&mut adc), Ok(None));
+    }
+
+    #[test]
+    fn read_ratio_large_values() {
+        let mut interpolator = interpolator(Config {
+            max_voltage: 3000,
+            precision: 16,
+            voltage_to_values: [(0, 100_000), (2000, 200_000)],
+            ..Default::default()
+        });
+
+        let expectations = [Transaction::read(0, 30000)];
+        let mut adc = adc(&expectations);
+
+        // About 168665, with a numerator too large for a `u32`
+        assert_eq!(
+            interpolator.read_ratio(&mut adc),
+            Ok(Some((7_369_000_000, 43690)))
+        );
+    }
+
+    #[test]
+    fn read_ratio_nearest() {
+        let mut interpolator = interpolator(Config {
+            interpolation: Interpolation::Nearest,
+            ..table_positive()
+        });
+
+        let expectations = [Transaction::read(0, 1000)];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_ratio(&mut adc), Ok(Some((30, 1))));
+    }
+
+    #[test]
+    fn plausible_range() {
+        let mut interpolator = interpolator(Config {
+            plausible_range: Some((300, 1300)),
+            ..table_negative()
+        });
+
+        let expectations = [
+            Transaction::read(0, 299),
+            Transaction::read(0, 300),
+            Transaction::read(0, 1301),
+            Transaction::read(0, 299),
+            Transaction::read(0, 614),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(
+            interpolator.read_detailed(&mut adc),
+            Ok(Reading::Implausible)
+        );
+        assert_eq!(interpolator.read_detailed(&mut adc), Ok(Reading::Below));
+        assert_eq!(
+            interpolator.read_detailed(&mut adc),
+            Ok(Reading::Implausible)
+        );
+        assert_eq!(interpolator.read(&mut adc), Ok(None));
+        assert_eq!(interpolator.read(&mut adc), Ok(Some(35)));
+    }
+
+    #[test]
+    fn plausible_range_ratio_and_code_hysteresis() {
+        let mut interpolator = interpolator(Config {
+            plausible_range: Some((1000, 1300)),
+            ..table_negative()
+        });
+
+        let expectations = [
+            Transaction::read(0, 614),
+            Transaction::read(0, 1126),
+            Transaction::read(0, 1126),
+            Transaction::read(0, 990),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_ratio(&mut adc), Ok(None));
+        assert_eq!(interpolator.read_ratio(&mut adc), Ok(Some((6130, 409))));
+        assert_eq!(
+            interpolator.read_code_hysteresis(&mut adc, 20),
+            Ok(Some(15))
+        );
+        // Within the band of the previous code, but implausible
+        assert_eq!(interpolator.read_code_hysteresis(&mut adc, 20), Ok(None));
+    }
+
+    #[test]
+    fn read_both_floor() {
+        let mut interpolator = interpolator(Config {
+            interpolation: Interpolation::Floor,
+            ..table_positive()
+        });
+
+        let expectations = [
+            Transaction::read(0, 1000),
+            Transaction::read(0, 1228),
+            Transaction::read(0, 1229),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_both(&mut adc), Ok(Some((30, 30))));
+        assert_eq!(interpolator.read_both(&mut adc), Ok(Some((40, 30))));
+        assert_eq!(interpolator.read_both(&mut adc), Ok(None));
+    }
+
+    #[test]
+    fn read_both_linear() {
+        let mut interpolator = interpolator(table_positive());
+
+        let expectations = [Transaction::read(0, 1000), Transaction::read(0, 1100)];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_both(&mut adc), Ok(Some((30, 34))));
+        assert_eq!(interpolator.read_both(&mut adc), Ok(Some((40, 36))));
+    }
+
+    #[test]
+    fn noise_estimate() {
+        let mut interpolator = interpolator(table_positive());
+
+        let expectations: Vec<_> = (0..20)
+            .map(|i| Transaction::read(0, if i % 2 == 0 { 614 } else { 618 }))
+            .collect();
+        let mut adc = adc(&expectations);
+
+        for _ in 0..7 {
+            interpolator.read(&mut adc).unwrap();
+        }
+        assert_eq!(interpolator.noise_estimate(), 0);
+
+        for _ in 7..20 {
+            interpolator.read(&mut adc).unwrap();
+        }
+        assert_eq!(interpolator.noise_estimate(), 2);
+    }
+
+    #[test]
+    fn i32_words() {
+        let pin = MockChan0 {};
+        let mut interpolator: AdcInterpolator<_, i32, 3> =
+            AdcInterpolator::new(pin, table_negative());
+
+        let expectations = [
+            Transaction::read(0, i32::MIN),
+            Transaction::read(0, 614),
+            Transaction::read(0, i32::MAX),
+        ];
+        let mut adc = Mock::new(&expectations);
+
+        assert_eq!(interpolator.read(&mut adc), Ok(None));
+        assert_eq!(interpolator.read(&mut adc), Ok(Some(35)));
+        assert_eq!(interpolator.read(&mut adc), Ok(None));
+    }
+
+    #[test]
+    fn signed_words() {
+        let pin = MockChan0 {};
+        let mut interpolator: AdcInterpolator<_, i16, 3> =
+            AdcInterpolator::new(pin, table_negative());
+
+        let expectations = [
+            Transaction::read(0, -614),
+            Transaction::read(0, 614),
+            Transaction::read(0, -1),
+            Transaction::read(0, 2000),
+            Transaction::read(0, -614),
+        ];
+        let mut adc = Mock::new(&expectations);
+
+        assert_eq!(interpolator.read(&mut adc), Ok(None));
+        assert_eq!(interpolator.read(&mut adc), Ok(Some(35)));
+        assert_eq!(interpolator.read_detailed(&mut adc), Ok(Reading::Below));
+        assert_eq!(interpolator.read_detailed(&mut adc), Ok(Reading::Above));
+        assert_eq!(interpolator.read_voltage(&mut adc), Ok(0));
+    }
+
+    #[test]
+    fn offset_output() {
+        let mut interpolator = interpolator(table_positive());
+
+        let expectations = [Transaction::read(0, 614), Transaction::read(0, 614)];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_code_hysteresis(&mut adc, 2), Ok(Some(20)));
+        assert_eq!(interpolator.offset_output(5), Ok(()));
+        assert_eq!(interpolator.read_code_hysteresis(&mut adc, 2), Ok(Some(25)));
+
+        assert_eq!(interpolator.offset_output(-20), Ok(()));
+        assert_eq!(interpolator.min_value(), 0);
+        assert_eq!(interpolator.max_value(), 25);
+
+        for _ in 0..3 {
+            assert_eq!(interpolator.offset_output(i32::MAX), Ok(()));
+        }
+        assert_eq!(interpolator.max_value(), u32::MAX);
+    }
+
+    #[test]
+    fn offset_output_narrow_values() {
+        let config: ValueConfig<3, u16> = ValueConfig {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [(100, 10), (200, 60_000), (300, 65_000)],
+            ..Default::default()
+        };
+        let mut interpolator: AdcInterpolator<MockChan0, u16, 3, u16> =
+            AdcInterpolator::new(MockChan0 {}, config);
+
+        assert_eq!(interpolator.offset_output(500), Ok(()));
+        assert_eq!(interpolator.point(2), Some((300, 65_500)));
+
+        assert_eq!(
+            interpolator.offset_output(50),
+            Err(ConfigError::ValueOutOfRange { index: 2 })
+        );
+        assert_eq!(interpolator.point(0), Some((100, 510)));
+        assert_eq!(interpolator.offset_output(-1000), Ok(()));
+        assert_eq!(interpolator.point(0), Some((100, 0)));
+    }
+
+    #[test]
+    fn read_ratiometric_out_of_range() {
+        let mut interpolator = interpolator(table_clamped());
+        let mut supply = MockChan1 {};
+
+        let expectations = [
+            Transaction::read(0, 2000),
+            Transaction::read(1, 2000),
+            Transaction::read(0, 4095),
+            Transaction::read(1, 4095),
+            Transaction::read(0, 300),
+            Transaction::read(1, 4095),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(
+            interpolator.read_ratiometric(&mut adc, &mut supply),
+            Ok(Some(40))
+        );
+        assert_eq!(
+            interpolator.read_ratiometric(&mut adc, &mut supply),
+            Ok(None)
+        );
+        assert_eq!(
+            interpolator.read_ratiometric(&mut adc, &mut supply),
+            Ok(None)
+        );
+        assert_eq!(interpolator.sample_count(), 6);
+    }
+
+    #[test]
+    fn read_ratiometric() {
+        let mut interpolator = interpolator(table_positive());
+        let mut supply = MockChan1 {};
+
+        let expectations = [
+            Transaction::read(0, 614),
+            Transaction::read(1, 4095),
+            Transaction::read(0, 307),
+            Transaction::read(1, 2047),
+            Transaction::read(0, 614),
+            Transaction::read(1, 0),
+            Transaction::read(0, 2000),
+            Transaction::read(1, 2000),
+            Transaction::read(0, 614),
+            Transaction::read(1, 0).with_error(MockError::Io(ErrorKind::InvalidData)),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(
+            interpolator.read_ratiometric(&mut adc, &mut supply),
+            Ok(Some(20))
+        );
+        assert_eq!(
+            interpolator.read_ratiometric(&mut adc, &mut supply),
+            Ok(Some(20))
+        );
+        assert_eq!(
+            interpolator.read_ratiometric(&mut adc, &mut supply),
+            Ok(None)
+        );
+        assert_eq!(
+            interpolator.read_ratiometric(&mut adc, &mut supply),
+            Ok(None)
+        );
+        assert!(interpolator
+            .read_ratiometric(&mut adc, &mut supply)
+            .is_err());
+    }
+
+    #[test]
+    fn process_code() {
+        let interpolator = interpolator(Config {
+            inl_correction: &[(0, 10)],
+            plausible_range: Some((1, 4000)),
+            ..table_positive()
+        });
+
+        assert_eq!(interpolator.process_code(0), None);
+        assert_eq!(interpolator.process_code(399), Some(10));
+        assert_eq!(interpolator.process_code(604), Some(20));
+        assert_eq!(interpolator.process_code(1218), Some(40));
+        assert_eq!(interpolator.process_code(1219), None);
+    }
+
+    #[test]
+    fn interpolate_in_voltage() {
+        // 100 mV per code, so the points round to codes 1 and 3
+        let config = Config {
+            max_voltage: 1600,
+            precision: 4,
+            voltage_to_values: [(150, 0), (350, 100)],
+            ..Default::default()
+        };
+        assert_read_ok(config, 2, Some(50));
+        assert_read_ok(config, 1, Some(0));
+
+        let config = Config {
+            interpolate_in_voltage: true,
+            ..config
+        };
+        assert_read_ok(config, 2, Some(25));
+        assert_read_ok(config, 3, Some(75));
+        assert_read_ok(config, 1, None);
+
+        let interpolator = interpolator(config);
+        assert_eq!(interpolator.process_code(1), None);
+        assert_eq!(interpolator.slopes().next(), Some((100, 2)));
+
+        let mut readings = [Reading::Saturated; 2];
+        interpolator.interpolate_codes(&[1, 4], &mut readings);
+        assert_eq!(readings, [Reading::Below, Reading::Above]);
+    }
+
+    #[test]
+    fn interpolate_iter() {
+        let interpolator = interpolator(table_positive());
+
+        let codes = [0, 409, 614, 1228, 1229].into_iter();
+        let values: Vec<_> = interpolator.interpolate_iter(codes).collect();
+
+        assert_eq!(values, [None, Some(10), Some(20), Some(40), None]);
+    }
+
+    #[test]
+    fn read_with_confidence() {
+        let mut interpolator = interpolator(table_positive());
+
+        let expectations = [
+            Transaction::read(0, 409),
+            Transaction::read(0, 614),
+            Transaction::read(0, 1023),
+            Transaction::read(0, 1228),
+            Transaction::read(0, 1229),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(
+            interpolator.read_with_confidence(&mut adc),
+            Ok(Some((10, 1000)))
+        );
+        assert_eq!(
+            interpolator.read_with_confidence(&mut adc),
+            Ok(Some((20, 0)))
+        );
+        assert_eq!(
+            interpolator.read_with_confidence(&mut adc),
+            Ok(Some((34, 3)))
+        );
+        assert_eq!(
+            interpolator.read_with_confidence(&mut adc),
+            Ok(Some((40, 1000)))
+        );
+        assert_eq!(interpolator.read_with_confidence(&mut adc), Ok(None));
+    }
+
+    #[test]
+    fn read_with_uncertainty() {
+        let mut interpolator = interpolator(table_positive());
+
+        let expectations = [
+            Transaction::read(0, 409),
+            Transaction::read(0, 614),
+            Transaction::read(0, 1023),
+            Transaction::read(0, 1023),
+            Transaction::read(0, 1229),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(
+            interpolator.read_with_uncertainty(&mut adc, 10),
+            Ok(Some((10, 0)))
+        );
+        assert_eq!(
+            interpolator.read_with_uncertainty(&mut adc, 41),
+            Ok(Some((20, 2)))
+        );
+        assert_eq!(
+            interpolator.read_with_uncertainty(&mut adc, 41),
+            Ok(Some((34, 1)))
+        );
+        assert_eq!(
+            interpolator.read_with_uncertainty(&mut adc, u32::MAX),
+            Ok(Some((34, 105_011_425)))
+        );
+        assert_eq!(interpolator.read_with_uncertainty(&mut adc, 41), Ok(None));
+    }
+
+    #[test]
+    fn read_trimmed() {
+        let mut interpolator = interpolator(table_positive());
+
+        let expectations = [
+            Transaction::read(0, 0),
+            Transaction::read(0, 600),
+            Transaction::read(0, 610),
+            Transaction::read(0, 4095),
+            Transaction::read(0, 620),
+            Transaction::read(0, 614),
+            Transaction::read(0, 614),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(
+            interpolator.read_trimmed::<_, _, 5>(&mut adc, 1),
+            Ok(Some(19))
+        );
+        assert_eq!(
+            interpolator.read_trimmed::<_, _, 2>(&mut adc, 0),
+            Ok(Some(20))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn read_trimmed_panics_if_trim_too_large() {
+        let mut interpolator = interpolator(table_positive());
+        let mut adc = adc(&[]);
+
+        let _ = interpolator.read_trimmed::<_, _, 4>(&mut adc, 2);
+    }
+
+    /// An ADC which always returns the same 32-bit code.
+    struct ConstantAdc(u32);
+
+    impl OneShot<MockAdc, u32, MockChan0> for ConstantAdc {
+        type Error = ();
+
+        fn read(&mut self, _pin: &mut MockChan0) -> nb::Result<u32, ()> {
+            Ok(self.0)
+        }
+    }
+
+    #[test]
+    fn read_averaged() {
+        let mut interpolator = interpolator(table_positive());
+
+        let expectations = [
+            Transaction::read(0, 409),
+            Transaction::read(0, 820),
+            Transaction::read(0, 614),
+            Transaction::read(0, 0).with_error(MockError::Io(ErrorKind::InvalidData)),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_averaged(&mut adc, 2), Ok(Some(20)));
+        assert!(interpolator.read_averaged(&mut adc, 2).is_err());
+        assert_eq!(interpolator.sample_count(), 3);
+    }
+
+    #[test]
+    fn nodes_crossed() {
+        let mut interpolator = interpolator(table_positive());
+        let expectations = [
+            Transaction::read(0, 0),
+            Transaction::read(0, 409),
+            Transaction::read(0, 408),
+            Transaction::read(0, 4095),
+            Transaction::read(0, 820),
+            Transaction::read(0, 819),
+            Transaction::read(0, 819),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.nodes_crossed(), 0);
+
+        let mut read = || {
+            interpolator.read(&mut adc).unwrap();
+            interpolator.nodes_crossed()
+        };
+
+        // Only one code has been read
+        assert_eq!(read(), 0);
+        assert_eq!(read(), 1);
+        assert_eq!(read(), 0);
+        // Out of range codes are counted too
+        assert_eq!(read(), 3);
+        assert_eq!(read(), 1);
+        assert_eq!(read(), 1);
+        assert_eq!(read(), 0);
+    }
+
+    /// A DAC which records the codes written to it, failing once
+    /// `fail_after` codes have been written.
+    struct RecordingDac {
+        codes: Vec<u32>,
+        fail_after: usize,
+    }
+
+    impl Dac for RecordingDac {
+        type Error = ();
+
+        fn max_code(&self) -> u32 {
+            4095
+        }
+
+        fn write(&mut self, code: u32) -> nb::Result<(), ()> {
+            if self.codes.len() == self.fail_after {
+                return Err(nb::Error::Other(()));
+            }
+
+            self.codes.push(code);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn transfer() {
+        let mut interpolator = interpolator(table_positive());
+        let expectations = [
+            Transaction::read(0, 409),
+            Transaction::read(0, 819),
+            Transaction::read(0, 0),
+            Transaction::read(0, 1228),
+            Transaction::read(0, 1228),
+            Transaction::read(0, 0).with_error(MockError::Io(ErrorKind::InvalidData)),
+        ];
+        let mut adc = adc(&expectations);
+        let mut dac = RecordingDac {
+            codes: Vec::new(),
+            fail_after: 3,
+        };
+
+        assert_eq!(interpolator.transfer(&mut adc, &mut dac), Ok(Some(0)));
+        assert_eq!(interpolator.transfer(&mut adc, &mut dac), Ok(Some(2730)));
+        assert_eq!(interpolator.transfer(&mut adc, &mut dac), Ok(None));
+        assert_eq!(interpolator.transfer(&mut adc, &mut dac), Ok(Some(4095)));
+        assert_eq!(
+            interpolator.transfer(&mut adc, &mut dac),
+            Err(TransferError::Dac(nb::Error::Other(())))
+        );
+        assert!(matches!(
+            interpolator.transfer(&mut adc, &mut dac),
+            Err(TransferError::Adc(_))
+        ));
+        assert_eq!(dac.codes, [0, 2730, 4095]);
+    }
+
+    #[test]
+    fn transfer_out_of_range() {
+        let config = Config {
+            out_of_range_low: OutOfRange::Clamp,
+            out_of_range_high: OutOfRange::Value(1000),
+            ..table_positive()
+        };
+        let mut interpolator = interpolator(config);
+        let expectations = [Transaction::read(0, 100), Transaction::read(0, 2000)];
+        let mut adc = adc(&expectations);
+        let mut dac = RecordingDac {
+            codes: Vec::new(),
+            fail_after: 2,
+        };
+
+        assert_eq!(interpolator.transfer(&mut adc, &mut dac), Ok(Some(0)));
+        assert_eq!(interpolator.transfer(&mut adc, &mut dac), Ok(Some(4095)));
+        assert_eq!(dac.codes, [0, 4095]);
+    }
+
+    #[test]
+    fn transfer_peak() {
+        let mut interpolator = interpolator(table_peak());
+        let expectations = [
+            Transaction::read(0, 409),
+            Transaction::read(0, 819),
+            Transaction::read(0, 1228),
+        ];
+        let mut adc = adc(&expectations);
+        let mut dac = RecordingDac {
+            codes: Vec::new(),
+            fail_after: 3,
+        };
+
+        // scaled from 10 to the peak of 50, rather than to 20 at the
+        // end of the table
+        assert_eq!(interpolator.transfer(&mut adc, &mut dac), Ok(Some(0)));
+        assert_eq!(interpolator.transfer(&mut adc, &mut dac), Ok(Some(4095)));
+        assert_eq!(interpolator.transfer(&mut adc, &mut dac), Ok(Some(1023)));
+        assert_eq!(dac.codes, [0, 4095, 1023]);
+    }
+
+    #[test]
+    fn read_map_err() {
+        #[derive(Debug, PartialEq)]
+        enum AppError {
+            Busy,
+            Failed(MockError),
+        }
+
+        let map = |error: nb::Error<MockError>| match error {
+            nb::Error::WouldBlock => AppError::Busy,
+            nb::Error::Other(error) => AppError::Failed(error),
+        };
+
+        let mut interpolator = interpolator(table_positive());
+        let expectations = [
+            Transaction::read(0, 614),
+            Transaction::read(0, 0).with_error(MockError::Io(ErrorKind::InvalidData)),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_map_err(&mut adc, map), Ok(Some(20)));
+        assert_eq!(
+            interpolator.read_map_err(&mut adc, map),
+            Err(AppError::Failed(MockError::Io(ErrorKind::InvalidData)))
+        );
+        assert_eq!(map(nb::Error::WouldBlock), AppError::Busy);
+    }
+
+    #[test]
+    fn read_muxed() {
+        let mut interpolator = interpolator(table_positive());
+        let expectations = [Transaction::read(0, 409), Transaction::read(0, 1228)];
+        let mut adc = adc(&expectations);
+        let mut selected = Vec::new();
+
+        assert_eq!(
+            interpolator.read_muxed(&mut adc, || selected.push(1)),
+            Ok(Some(10))
+        );
+        assert_eq!(
+            interpolator.read_muxed(&mut adc, || selected.push(2)),
+            Ok(Some(40))
+        );
+        assert_eq!(selected, [1, 2]);
+    }
+
+    #[test]
+    fn values_monotonic() {
+        let monotonic = |voltage_to_values| {
+            let config = Config {
+                voltage_to_values,
+                ..table_positive()
+            };
+
+            interpolator(config).values_monotonic()
+        };
+
+        assert_eq!(
+            monotonic([(100, 10), (200, 30), (300, 40)]),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            monotonic([(100, 10), (200, 10), (300, 40)]),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            monotonic([(100, 40), (200, 40), (300, 10)]),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            monotonic([(100, 5), (200, 5), (300, 5)]),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(monotonic([(100, 10), (200, 30), (300, 20)]), None);
+        assert_eq!(
+            monotonic([(100, 30), (200, 30), (300, 40)]),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            monotonic([(100, 30), (200, 10), (300, 10)]),
+            Some(Ordering::Greater)
+        );
+    }
+
+    #[test]
+    fn read_trend() {
+        let mut interpolator = interpolator(table_positive());
+        let expectations = [
+            Transaction::read(0, 0),
+            Transaction::read(0, 614),
+            Transaction::read(0, 819),
+            Transaction::read(0, 820),
+            Transaction::read(0, 0),
+            Transaction::read(0, 409),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_trend(&mut adc), Ok((None, Trend::Steady)));
+        assert_eq!(
+            interpolator.read_trend(&mut adc),
+            Ok((Some(20), Trend::Steady))
+        );
+        assert_eq!(
+            interpolator.read_trend(&mut adc),
+            Ok((Some(30), Trend::Rising))
+        );
+        assert_eq!(
+            interpolator.read_trend(&mut adc),
+            Ok((Some(30), Trend::Steady))
+        );
+        assert_eq!(interpolator.read_trend(&mut adc), Ok((None, Trend::Steady)));
+        assert_eq!(
+            interpolator.read_trend(&mut adc),
+            Ok((Some(10), Trend::Falling))
+        );
+    }
+
+    #[test]
+    fn read_with_discard() {
+        let mut interpolator = interpolator(table_positive());
+
+        let expectations = [
+            Transaction::read(0, 4095),
+            Transaction::read(0, 0),
+            Transaction::read(0, 409),
+            Transaction::read(0, 820),
+            Transaction::read(0, 819),
+            Transaction::read(0, 0).with_error(MockError::Io(ErrorKind::InvalidData)),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_with_discard(&mut adc, 2, 2), Ok(Some(20)));
+        assert_eq!(interpolator.read_with_discard(&mut adc, 0, 1), Ok(Some(30)));
+        assert!(interpolator.read_with_discard(&mut adc, 1, 1).is_err());
+        assert_eq!(interpolator.sample_count(), 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn read_with_discard_panics_if_average_is_zero() {
+        let mut interpolator = interpolator(table_positive());
+        let mut adc = adc(&[]);
+
+        let _ = interpolator.read_with_discard(&mut adc, 2, 0);
+    }
+
+    #[test]
+    fn read_averaged_does_not_overflow() {
+        let config = Config {
+            max_voltage: 1000,
+            precision: 16,
+            voltage_to_values: [(0, 0), (999, 999)],
+            ..Default::default()
+        };
+        let mut interpolator: AdcInterpolator<_, u32, 2> =
+            AdcInterpolator::new(MockChan0 {}, config);
+        let code = 65_000;
+        let mut adc = ConstantAdc(code);
+
+        let value = interpolator.read_averaged(&mut adc, 1 << 20);
+        // The sum of the codes doesn't fit in a `u32`
+        assert_eq!(value, Ok(Some(991)));
+        assert_eq!(value, Ok(interpolator.process_code(code)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn read_averaged_panics_if_no_samples() {
+        let mut interpolator = interpolator(table_positive());
+        let expectations = [];
+        let mut adc = adc(&expectations);
+
+        let _ = interpolator.read_averaged(&mut adc, 0);
+    }
+
+    /// An ADC which is busy for one poll before each conversion.
+    #[cfg(feature = "async")]
+    struct SlowAdc {
+        codes: Vec<u16>,
+        busy: bool,
+    }
+
+    #[cfg(feature = "async")]
+    impl OneShot<MockAdc, u16, MockChan0> for SlowAdc {
+        type Error = ();
+
+        fn read(&mut self, _pin: &mut MockChan0) -> nb::Result<u16, ()> {
+            self.busy = !self.busy;
+
+            if self.busy {
+                Err(nb::Error::WouldBlock)
+            } else {
+                self.codes.pop().ok_or(nb::Error::Other(()))
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "async")]
+    fn read_averaged_async() {
+        let mut interpolator = interpolator(table_positive());
+        let mut adc = SlowAdc {
+            codes: vec![1229, 819, 409],
+            busy: false,
+        };
+
+        let (value, pending) = block_on(interpolator.read_averaged_async(&mut adc, 2));
+        assert_eq!(value, Ok(Some(20)));
+        assert_eq!(pending, 2);
+
+        // The remaining code is followed by an error
+        let (value, _) = block_on(interpolator.read_averaged_async(&mut adc, 2));
+        assert_eq!(value, Err(()));
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "async")]
+    fn read_averaged_async_panics_if_no_samples() {
+        let mut interpolator = interpolator(table_positive());
+        let mut adc = SlowAdc {
+            codes: vec![],
+            busy: false,
+        };
+
+        let _ = block_on(interpolator.read_averaged_async(&mut adc, 0));
+    }
+
+    #[test]
+    fn read_clamped_code() {
+        let mut interpolator = interpolator(Config {
+            inl_correction: &[(0, -10)],
+            plausible_range: Some((500, 600)),
+            ..table_positive()
+        });
+
+        let expectations = [
+            Transaction::read(0, 0),
+            Transaction::read(0, 419),
+            Transaction::read(0, 624),
+            Transaction::read(0, 4095),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_clamped_code(&mut adc), Ok(10));
+        assert_eq!(interpolator.read_clamped_code(&mut adc), Ok(10));
+        assert_eq!(interpolator.read_clamped_code(&mut adc), Ok(20));
+        assert_eq!(interpolator.read_clamped_code(&mut adc), Ok(40));
+    }
+
+    #[test]
+    fn read_clamped_code_unsorted() {
+        let config = table_invalid();
+        let mut interpolator: AdcInterpolator<_, u16, 3> =
+            AdcInterpolator::from_codes(MockChan0 {}, config, config.codes().unwrap());
+
+        let expectations = [Transaction::read(0, 819)];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_clamped_code(&mut adc), Ok(40));
+    }
+
+    #[test]
+    fn read_quantized() {
+        let mut interpolator = interpolator(table_positive());
+
+        let expectations = [
+            Transaction::read(0, 409),
+            Transaction::read(0, 614),
+            Transaction::read(0, 1000),
+            Transaction::read(0, 1100),
+            Transaction::read(0, 1100),
+            Transaction::read(0, 2000),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_quantized(&mut adc, 10), Ok(Some(10)));
+        assert_eq!(interpolator.read_quantized(&mut adc, 10), Ok(Some(20)));
+        assert_eq!(interpolator.read_quantized(&mut adc, 10), Ok(Some(30)));
+        assert_eq!(interpolator.read_quantized(&mut adc, 3), Ok(Some(36)));
+        assert_eq!(interpolator.read_quantized(&mut adc, 0), Ok(Some(36)));
+        assert_eq!(interpolator.read_quantized(&mut adc, 10), Ok(None));
+    }
+
+    #[test]
+    fn read_quantized_saturates() {
+        let mut interpolator = interpolator(Config {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [(100, u32::MAX - 1), (200, u32::MAX)],
+            ..Default::default()
+        });
+        let expectations = [Transaction::read(0, 819)];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(
+            interpolator.read_quantized(&mut adc, 10),
+            Ok(Some(4_294_967_290))
+        );
+    }
+
+    #[test]
+    fn out_of_range_values() {
+        let config = Config {
+            out_of_range_low: OutOfRange::Value(0),
+            out_of_range_high: OutOfRange::Value(1000),
+            ..table_positive()
+        };
+        assert_read_ok(config, 408, Some(0));
+        assert_read_ok(config, 409, Some(10));
+        assert_read_ok(config, 1228, Some(40));
+        assert_read_ok(config, 1229, Some(1000));
+
+        let config = Config {
+            out_of_range_high: OutOfRange::None,
+            ..config
+        };
+        assert_read_ok(config, 1229, None);
+
+        let pin = MockChan0 {};
+        let interpolator: AdcInterpolator<_, i16, 3> = AdcInterpolator::new(pin, config);
+        assert_eq!(interpolator.process_code(-1), Some(0));
+    }
+
+    #[test]
+    fn clamp_margin() {
+        let config = Config {
+            clamp_margin: 16,
+            ..table_negative()
+        };
+        assert_read_ok(config, 392, None);
+        assert_read_ok(config, 393, Some(40));
+        assert_read_ok(config, 408, Some(40));
+        assert_read_ok(config, 1244, Some(10));
+        assert_read_ok(config, 1245, None);
+
+        let config = Config {
+            out_of_range_high: OutOfRange::Value(0),
+            ..config
+        };
+        assert_read_ok(config, 1244, Some(10));
+        assert_read_ok(config, 1245, Some(0));
+
+        let config = Config {
+            voltage_to_values: [(0, 40), (200, 30), (300, 10)],
+            ..config
+        };
+        let pin = MockChan0 {};
+        let interpolator: AdcInterpolator<_, i16, 3> = AdcInterpolator::new(pin, config);
+        assert_eq!(interpolator.process_code(-16), Some(40));
+        assert_eq!(interpolator.process_code(-17), None);
+    }
+
+    #[test]
+    fn asymmetric_out_of_range() {
+        let config = Config {
+            out_of_range_low: OutOfRange::Clamp,
+            ..table_positive()
+        };
+        assert_read_ok(config, 0, Some(10));
+        assert_read_ok(config, 1229, None);
+
+        let config = table_negative().with_out_of_range(OutOfRange::Clamp);
+        assert_read_ok(config, 408, Some(40));
+        assert_read_ok(config, 1229, Some(10));
+    }
+
+    #[test]
+    fn sample_count() {
+        let mut interpolator = interpolator(table_positive());
+        let mut supply = MockChan1 {};
+
+        let expectations = [
+            Transaction::read(0, 614),
+            Transaction::read(0, 614),
+            Transaction::read(0, 614),
+            Transaction::read(0, 614),
+            Transaction::read(1, 4095),
+            Transaction::read(0, 0).with_error(MockError::Io(ErrorKind::InvalidData)),
+        ];
+        let mut adc = adc(&expectations);
+
+        interpolator.read(&mut adc).unwrap();
+        interpolator.read_n::<_, _, 2>(&mut adc).unwrap();
+        assert_eq!(interpolator.sample_count(), 3);
+
+        interpolator
+            .read_ratiometric(&mut adc, &mut supply)
+            .unwrap();
+        assert_eq!(interpolator.sample_count(), 5);
+
+        assert!(interpolator.read(&mut adc).is_err());
+        assert_eq!(interpolator.sample_count(), 5);
+
+        interpolator.reset_count();
+        assert_eq!(interpolator.sample_count(), 0);
+    }
+
+    #[test]
+    fn value_at_voltage() {
+        let interpolator = interpolator(table_positive());
+
+        assert_eq!(interpolator.value_at_voltage(99), None);
+        assert_eq!(interpolator.value_at_voltage(100), Some(10));
+        assert_eq!(interpolator.value_at_voltage(250), Some(35));
+        assert_eq!(interpolator.value_at_voltage(300), Some(40));
+        assert_eq!(interpolator.value_at_voltage(301), None);
+    }
+
+    #[test]
+    fn value_at_voltage_in_voltage() {
+        let interpolator = interpolator(Config {
+            interpolate_in_voltage: true,
+            out_of_range_low: OutOfRange::Value(0),
+            inl_correction: &[(0, 100)],
+            ..table_positive()
+        });
+
+        assert_eq!(interpolator.value_at_voltage(50), Some(0));
+        assert_eq!(interpolator.value_at_voltage(150), Some(20));
+        assert_eq!(interpolator.value_at_voltage(250), Some(35));
+    }
+
+    #[test]
+    fn self_check() {
+        let interpolator = interpolator(table_positive());
+
+        assert!(interpolator.self_check(409, 10, 0));
+        assert!(!interpolator.self_check(409, 11, 0));
+        assert!(interpolator.self_check(614, 25, 5));
+        assert!(interpolator.self_check(614, 15, 5));
+        assert!(!interpolator.self_check(614, 26, 5));
+        assert!(!interpolator.self_check(2000, 40, u32::MAX));
+    }
+
+    #[test]
+    fn bracket_indices() {
+        let interpolator = interpolator(table_positive());
+
+        assert_eq!(interpolator.bracket_indices(408), None);
+        assert_eq!(interpolator.bracket_indices(409), Some((0, 1)));
+        assert_eq!(interpolator.bracket_indices(614), Some((0, 1)));
+        assert_eq!(interpolator.bracket_indices(819), Some((0, 1)));
+        assert_eq!(interpolator.bracket_indices(820), Some((1, 2)));
+        assert_eq!(interpolator.bracket_indices(1228), Some((1, 2)));
+        assert_eq!(interpolator.bracket_indices(1229), None);
+    }
+
+    #[test]
+    fn bracket_indices_upper_boundary() {
+        let interpolator = interpolator(Config {
+            boundary: Boundary::Upper,
+            ..table_positive()
+        });
+
+        assert_eq!(interpolator.bracket_indices(819), Some((1, 2)));
+    }
+
+    #[test]
+    fn on_plateau() {
+        let plateau = interpolator(Config {
             max_voltage: 1000,
             precision: 12,
-            voltage_to_values: [(100, 10), (200, 30), (300, 40)],
-        }
+            voltage_to_values: [(100, 10), (200, 30), (300, 30)],
+            ..Default::default()
+        });
+
+        assert!(!plateau.on_plateau(408));
+        assert!(!plateau.on_plateau(614));
+        assert!(!plateau.on_plateau(819));
+        assert!(plateau.on_plateau(820));
+        assert!(plateau.on_plateau(1228));
+        assert!(!plateau.on_plateau(1229));
+        assert!(!interpolator(table_positive()).on_plateau(1000));
     }
 
-    fn table_negative() -> Config<3> {
-        Config {
+    #[test]
+    fn read_microvolts() {
+        let mut interpolator = interpolator(table_positive());
+
+        let expectations = [
+            Transaction::read(0, 0),
+            Transaction::read(0, 614),
+            Transaction::read(0, 10000),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_microvolts(&mut adc), Ok(0));
+        assert_eq!(interpolator.read_microvolts(&mut adc), Ok(149902));
+        assert_eq!(interpolator.read_microvolts(&mut adc), Ok(2441406));
+    }
+
+    #[test]
+    fn nearest() {
+        let config = Config {
+            interpolation: Interpolation::Nearest,
+            ..table_negative()
+        };
+
+        assert_read_ok(config, 409, Some(40));
+        assert_read_ok(config, 613, Some(40));
+        assert_read_ok(config, 614, Some(30));
+        assert_read_ok(config, 1228, Some(10));
+        assert_read_ok(config, 1229, None);
+    }
+
+    #[test]
+    fn floor() {
+        let config = Config {
+            interpolation: Interpolation::Floor,
+            ..table_negative()
+        };
+
+        assert_read_ok(config, 409, Some(40));
+        assert_read_ok(config, 818, Some(40));
+        assert_read_ok(config, 820, Some(30));
+        assert_read_ok(config, 408, None);
+    }
+
+    #[test]
+    fn boundary() {
+        let config = Config {
+            interpolation: Interpolation::Floor,
+            ..table_negative()
+        };
+        assert_read_ok(config, 819, Some(40));
+
+        let config = Config {
+            boundary: Boundary::Upper,
+            ..config
+        };
+        assert_read_ok(config, 409, Some(40));
+        assert_read_ok(config, 819, Some(30));
+        assert_read_ok(config, 1228, Some(30));
+
+        let config = Config {
+            interpolation: Interpolation::Linear,
+            ..config
+        };
+        assert_read_ok(config, 819, Some(30));
+    }
+
+    #[test]
+    fn rounding() {
+        let rounded = |rounding| Config {
+            rounding,
+            ..table_positive()
+        };
+        assert_read_ok(rounded(RoundingMode::Truncate), 500, Some(14));
+        assert_read_ok(rounded(RoundingMode::Floor), 500, Some(14));
+        assert_read_ok(rounded(RoundingMode::Ceil), 500, Some(15));
+        assert_read_ok(rounded(RoundingMode::Nearest), 500, Some(14));
+
+        let rounded = |rounding| Config {
+            rounding,
+            ..table_negative()
+        };
+        assert_read_ok(rounded(RoundingMode::Truncate), 500, Some(38));
+        assert_read_ok(rounded(RoundingMode::Floor), 500, Some(37));
+        assert_read_ok(rounded(RoundingMode::Ceil), 500, Some(38));
+        assert_read_ok(rounded(RoundingMode::Nearest), 500, Some(38));
+        assert_read_ok(rounded(RoundingMode::Nearest), 1000, Some(21));
+    }
+
+    #[test]
+    fn clamp_output() {
+        let config = Config {
+            out_of_range_low: OutOfRange::Value(0),
+            out_of_range_high: OutOfRange::Value(1000),
+            clamp_output: true,
+            ..table_positive()
+        };
+
+        assert_read_ok(config, 100, Some(10));
+        assert_read_ok(config, 614, Some(20));
+        assert_read_ok(config, 2000, Some(40));
+
+        let config = Config {
+            clamp_output: false,
+            ..config
+        };
+        assert_read_ok(config, 100, Some(0));
+        assert_read_ok(config, 2000, Some(1000));
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn clamp_output_catmull_rom() {
+        let config = Config {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [(0, 0), (100, 100), (200, 100), (300, 0)],
+            interpolation: Interpolation::CatmullRom,
+            ..Default::default()
+        };
+        assert_read_ok(config, 614, Some(113));
+
+        let config = Config {
+            clamp_output: true,
+            ..config
+        };
+        assert_read_ok(config, 614, Some(100));
+    }
+
+    #[test]
+    fn segment_interpolation() {
+        let config = Config {
+            segment_interpolation: &[Interpolation::Linear, Interpolation::Floor],
+            ..table_positive()
+        };
+        assert_read_ok(config, 614, Some(20));
+        assert_read_ok(config, 1100, Some(30));
+
+        let config = Config {
+            interpolation: Interpolation::Nearest,
+            segment_interpolation: &[Interpolation::Floor],
+            ..table_positive()
+        };
+        assert_read_ok(config, 800, Some(10));
+        assert_read_ok(config, 1200, Some(40));
+    }
+
+    #[test]
+    fn read_q16() {
+        let mut interpolator = interpolator(table_positive());
+
+        let expectations = [
+            Transaction::read(0, 409),
+            Transaction::read(0, 500),
+            Transaction::read(0, 1228),
+            Transaction::read(0, 1229),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_q16(&mut adc), Ok(Some(10 << 16)));
+        assert_eq!(interpolator.read_q16(&mut adc), Ok(Some(946_275)));
+        assert_eq!(interpolator.read_q16(&mut adc), Ok(Some(40 << 16)));
+        assert_eq!(interpolator.read_q16(&mut adc), Ok(None));
+    }
+
+    #[test]
+    fn read_q16_saturates() {
+        let mut interpolator = interpolator(Config {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [(100, 0), (300, 1_000_000)],
+            ..Default::default()
+        });
+
+        let expectations = [Transaction::read(0, 1228)];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_q16(&mut adc), Ok(Some(i32::MAX)));
+    }
+
+    #[test]
+    fn read_ratio_segment_interpolation() {
+        let mut interpolator = interpolator(Config {
+            segment_interpolation: &[Interpolation::Linear, Interpolation::Floor],
+            ..table_positive()
+        });
+
+        let expectations = [Transaction::read(0, 614), Transaction::read(0, 1100)];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_ratio(&mut adc), Ok(Some((8200, 410))));
+        assert_eq!(interpolator.read_ratio(&mut adc), Ok(Some((30, 1))));
+    }
+
+    #[test]
+    fn smoothstep() {
+        let config = Config {
+            interpolation: Interpolation::Smoothstep,
+            ..table_negative()
+        };
+
+        assert_read_ok(config, 409, Some(40));
+        assert_read_ok(config, 450, Some(40));
+        assert_read_ok(config, 614, Some(35));
+        assert_read_ok(config, 778, Some(31));
+        assert_read_ok(config, 819, Some(30));
+        assert_read_ok(config, 1228, Some(10));
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn catmull_rom() {
+        let config = Config {
+            max_voltage: 4096,
+            precision: 12,
+            voltage_to_values: [(0, 0), (100, 100), (200, 400), (300, 900)],
+            interpolation: Interpolation::CatmullRom,
+            ..Default::default()
+        };
+
+        assert_read_ok(config, 0, Some(0));
+        assert_read_ok(config, 50, Some(38));
+        assert_read_ok(config, 100, Some(100));
+        assert_read_ok(config, 150, Some(225));
+        assert_read_ok(config, 200, Some(400));
+        assert_read_ok(config, 250, Some(638));
+        assert_read_ok(config, 300, Some(900));
+        assert_read_ok(config, 301, None);
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn catmull_rom_linear() {
+        let config = Config {
+            max_voltage: 4096,
+            precision: 12,
+            voltage_to_values: [(0, 0), (100, 50), (200, 100)],
+            interpolation: Interpolation::CatmullRom,
+            ..Default::default()
+        };
+
+        assert_read_ok(config, 50, Some(25));
+        assert_read_ok(config, 150, Some(75));
+    }
+
+    #[test]
+    fn narrow_values() {
+        let config: ValueConfig<3, u8> = ValueConfig {
             max_voltage: 1000,
             precision: 12,
             voltage_to_values: [(100, 40), (200, 30), (300, 10)],
+            ..Default::default()
+        };
+        let mut interpolator: AdcInterpolator<MockChan0, u16, 3, u8> =
+            AdcInterpolator::new(MockChan0 {}, config);
+        let mut adc = adc(&[Transaction::read(0, 614)]);
+
+        assert_eq!(interpolator.read(&mut adc), Ok(Some(35)));
+        assert_eq!(interpolator.max_value(), 40);
+    }
+
+    #[test]
+    fn narrow_values_size() {
+        // each `u16` value saves two bytes over a `u32`, since values
+        // aren't stored alongside the voltages of the points
+        assert_eq!(
+            size_of::<AdcInterpolator<MockChan0, u16, 16, u16>>() + 16 * 2,
+            size_of::<AdcInterpolator<MockChan0, u16, 16>>()
+        );
+        assert_eq!(
+            size_of::<AdcInterpolator<MockChan0, u16, 16, u8>>() + 16 * 3,
+            size_of::<AdcInterpolator<MockChan0, u16, 16>>()
+        );
+    }
+
+    #[test]
+    fn u16_values() {
+        let config: ValueConfig<3, u16> = ValueConfig {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [(100, 0), (200, 60_000), (300, u16::MAX)],
+            ..Default::default()
+        };
+        let mut interpolator: AdcInterpolator<MockChan0, u16, 3, u16> =
+            AdcInterpolator::new(MockChan0 {}, config);
+        let mut adc = adc(&[Transaction::read(0, 614), Transaction::read(0, 1228)]);
+
+        // 60,000 * 205 doesn't fit in a `u16`
+        assert_eq!(interpolator.read(&mut adc), Ok(Some(30_000)));
+        assert_eq!(interpolator.read(&mut adc), Ok(Some(65_535)));
+        assert_eq!(interpolator.value_range(), (0, 65_535));
+    }
+
+    #[test]
+    fn read_value() {
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        enum Level {
+            #[default]
+            Low,
+            Medium,
+            High,
         }
+
+        let config = ValueConfig {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [(100, Level::Low), (200, Level::Medium), (300, Level::High)],
+            interpolation: Interpolation::Floor,
+            ..Default::default()
+        };
+        let mut interpolator: AdcInterpolator<MockChan0, u16, 3, Level> =
+            AdcInterpolator::new(MockChan0 {}, config);
+        let expectations = [
+            Transaction::read(0, 409),
+            Transaction::read(0, 1000),
+            Transaction::read(0, 1228),
+            Transaction::read(0, 0),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_value(&mut adc), Ok(Some(Level::Low)));
+        assert_eq!(interpolator.read_value(&mut adc), Ok(Some(Level::Medium)));
+        assert_eq!(interpolator.read_value(&mut adc), Ok(Some(Level::Medium)));
+        assert_eq!(interpolator.read_value(&mut adc), Ok(None));
     }
 
-    fn table_invalid() -> Config<3> {
-        Config {
+    #[test]
+    fn read_value_faults() {
+        let config = ValueConfig {
             max_voltage: 1000,
             precision: 12,
-            voltage_to_values: [(300, 40), (200, 30), (100, 10)],
+            voltage_to_values: [(0, 'a'), (500, 'b'), (1000, 'c')],
+            interpolation: Interpolation::Floor,
+            plausible_range: Some((0, 3000)),
+            rail_is_error: true,
+            ..Default::default()
+        };
+        let mut interpolator: AdcInterpolator<MockChan0, u16, 3, char> =
+            AdcInterpolator::new(MockChan0 {}, config);
+        let expectations = [
+            Transaction::read(0, 1000),
+            Transaction::read(0, 0),
+            Transaction::read(0, 3500),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_value(&mut adc), Ok(Some('a')));
+        // railed, though within the table
+        assert_eq!(interpolator.read_value(&mut adc), Ok(None));
+        // implausible, though within the table
+        assert_eq!(interpolator.read_value(&mut adc), Ok(None));
+    }
+
+    #[test]
+    fn read_value_bands() {
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        enum Level {
+            #[default]
+            Low,
+            Medium,
+            High,
         }
+
+        // Three bands, from 100 mV to 200 mV, 200 mV to 300 mV and 300
+        // mV to 400 mV, with the last point marking the top of the
+        // last band
+        let config = ValueConfig {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [
+                (100, Level::Low),
+                (200, Level::Medium),
+                (300, Level::High),
+                (400, Level::High),
+            ],
+            interpolation: Interpolation::Floor,
+            boundary: Boundary::Upper,
+            ..Default::default()
+        };
+        let mut interpolator: AdcInterpolator<MockChan0, u16, 4, Level> =
+            AdcInterpolator::new(MockChan0 {}, config);
+        let expectations = [
+            Transaction::read(0, 818),
+            Transaction::read(0, 819),
+            Transaction::read(0, 1300),
+            Transaction::read(0, 1638),
+            Transaction::read(0, 1639),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_value(&mut adc), Ok(Some(Level::Low)));
+        assert_eq!(interpolator.read_value(&mut adc), Ok(Some(Level::Medium)));
+        assert_eq!(interpolator.read_value(&mut adc), Ok(Some(Level::High)));
+        assert_eq!(interpolator.read_value(&mut adc), Ok(Some(Level::High)));
+        assert_eq!(interpolator.read_value(&mut adc), Ok(None));
+
+        // Linear interpolation falls back to the nearest point
+        let config = ValueConfig {
+            interpolation: Interpolation::Linear,
+            boundary: Boundary::Lower,
+            ..config
+        };
+        let mut interpolator: AdcInterpolator<MockChan0, u16, 4, Level> =
+            AdcInterpolator::new(MockChan0 {}, config);
+        let expectations = [Transaction::read(0, 1100), Transaction::read(0, 1000)];
+        let mut adc = Mock::new(&expectations);
+
+        assert_eq!(interpolator.read_value(&mut adc), Ok(Some(Level::High)));
+        assert_eq!(interpolator.read_value(&mut adc), Ok(Some(Level::Medium)));
     }
 
-    fn interpolator<const LENGTH: usize>(
-        config: Config<LENGTH>,
-    ) -> AdcInterpolator<MockChan0, u16, LENGTH> {
-        let pin = MockChan0 {};
-        AdcInterpolator::new(pin, config)
+    #[test]
+    fn read_accumulate() {
+        let mut interpolator = interpolator(table_negative());
+        let expectations = [
+            Transaction::read(0, 614),
+            Transaction::read(0, 0),
+            Transaction::read(0, 409),
+            Transaction::read(0, 819),
+        ];
+        let mut adc = adc(&expectations);
+
+        assert_eq!(interpolator.read_accumulate(&mut adc, 2), Ok(70));
+        assert_eq!(interpolator.read_accumulate(&mut adc, 2), Ok(70));
+        assert_eq!(interpolator.read_accumulate(&mut adc, 1), Ok(110));
+
+        interpolator.reset_accumulator();
+        assert_eq!(interpolator.read_accumulate(&mut adc, 3), Ok(90));
     }
 
-    fn adc(expectations: &[Transaction<u16>]) -> Generic<Transaction<u16>> {
-        Mock::new(expectations)
+    #[test]
+    fn point() {
+        let interpolator = interpolator(table_negative());
+
+        assert_eq!(interpolator.point(0), Some((100, 40)));
+        assert_eq!(interpolator.point(2), Some((300, 10)));
+        assert_eq!(interpolator.point(3), None);
     }
 
-    fn assert_read_ok<const LENGTH: usize>(
-        config: Config<LENGTH>,
-        value: u16,
-        expected: Option<u32>,
-    ) {
+    #[test]
+    fn set_point() {
+        let mut interpolator = interpolator(table_negative());
+
+        assert_eq!(interpolator.set_point(0, 0, 50), Ok(()));
+        assert_eq!(interpolator.set_point(2, 400, 0), Ok(()));
+        assert_eq!(interpolator.point(0), Some((0, 50)));
+        assert_eq!(interpolator.point(2), Some((400, 0)));
+
+        let expectations = [Transaction::read(0, 0), Transaction::read(0, 1638)];
+        let mut adc = adc(&expectations);
+        assert_eq!(interpolator.read(&mut adc), Ok(Some(50)));
+        assert_eq!(interpolator.read(&mut adc), Ok(Some(0)));
+    }
+
+    #[test]
+    fn set_point_validates() {
+        let config = Config {
+            min_code_spacing: 100,
+            ..table_negative()
+        };
         let mut interpolator = interpolator(config);
-        let expectations = [Transaction::read(0, value)];
+
+        assert_eq!(
+            interpolator.set_point(1, 100, 35),
+            Err(ConfigError::NotAscending { index: 1 })
+        );
+        assert_eq!(
+            interpolator.set_point(1, 300, 35),
+            Err(ConfigError::NotAscending { index: 2 })
+        );
+        assert_eq!(
+            interpolator.set_point(1, 120, 35),
+            Err(ConfigError::PointsTooClose { index: 1 })
+        );
+        assert_eq!(interpolator.point(1), Some((200, 30)));
+        assert_eq!(interpolator.set_point(1, 150, 35), Ok(()));
+    }
+
+    #[test]
+    fn set_point_invalid() {
+        let mut interpolator = interpolator(table_negative());
+
+        assert_eq!(
+            interpolator.set_point(3, 400, 0),
+            Err(ConfigError::IndexOutOfRange { index: 3 })
+        );
+        assert_eq!(
+            interpolator.set_point(1, 50, 0),
+            Err(ConfigError::NotAscending { index: 1 })
+        );
+        assert_eq!(
+            interpolator.set_point(1, 350, 0),
+            Err(ConfigError::NotAscending { index: 2 })
+        );
+        assert_eq!(
+            interpolator.set_point(2, 20000, 0),
+            Err(ConfigError::CodeOutOfRange { index: 2 })
+        );
+        assert_eq!(interpolator.point(1), Some((200, 30)));
+        assert_eq!(interpolator.point(2), Some((300, 10)));
+    }
+
+    #[test]
+    fn interpolate_codes() {
+        let interpolator = interpolator(table_negative());
+        let codes = [0, 408, 409, 614, 1228, 1229, 4095];
+        let mut readings = [Reading::Saturated; 8];
+
+        assert_eq!(interpolator.interpolate_codes(&codes, &mut readings), 7);
+        assert_eq!(
+            readings,
+            [
+                Reading::Saturated,
+                Reading::Below,
+                Reading::InRange(40),
+                Reading::InRange(35),
+                Reading::InRange(10),
+                Reading::Above,
+                Reading::Saturated,
+                Reading::Saturated,
+            ]
+        );
+
+        let mut readings = [Reading::Saturated; 2];
+        assert_eq!(
+            interpolator.interpolate_codes(&codes[3..], &mut readings),
+            2
+        );
+        assert_eq!(readings, [Reading::InRange(35), Reading::InRange(10)]);
+    }
+
+    #[test]
+    fn slopes() {
+        let slopes: Vec<_> = interpolator(table_positive()).slopes().collect();
+
+        assert_eq!(slopes, [(20, 410), (10, 409)]);
+
+        let config = Config {
+            voltage_to_values: [(100, 10), (200, 10), (200, 5)],
+            ..table_positive()
+        };
+        let slopes: Vec<_> = interpolator(config).slopes().collect();
+
+        assert_eq!(slopes, [(0, 410), (-5, 0)]);
+    }
+
+    #[test]
+    fn state() {
+        let mut interpolator = interpolator(table_positive());
+        let initial = interpolator.state();
+
+        let expectations = [
+            Transaction::read(0, 614),
+            Transaction::read(0, 614),
+            Transaction::read(0, 614),
+            Transaction::read(0, 614),
+            Transaction::read(0, 700),
+            Transaction::read(0, 819),
+            Transaction::read(0, 819),
+        ];
         let mut adc = adc(&expectations);
 
-        assert_eq!(interpolator.read(&mut adc), Ok(expected))
+        interpolator.read_accumulate(&mut adc, 10).unwrap();
+        interpolator.read_code_hysteresis(&mut adc, 100).unwrap();
+        interpolator.read_checked_delta(&mut adc, 100).unwrap();
+        interpolator.read_rate(&mut adc, 10).unwrap();
+
+        let state = interpolator.state();
+        assert_ne!(state, initial);
+
+        interpolator.restore_state(initial);
+        assert_eq!(interpolator.read_accumulate(&mut adc, 0), Ok(0));
+
+        interpolator.restore_state(state);
+        assert_eq!(interpolator.read_accumulate(&mut adc, 0), Ok(200));
+        assert_eq!(
+            interpolator.read_code_hysteresis(&mut adc, 300),
+            Ok(Some(20))
+        );
+        assert_eq!(interpolator.state().previous, state.previous);
     }
 
     #[test]
-    #[should_panic]
-    fn panics_if_unsorted_tabled() {
-        interpolator(table_invalid());
+    fn crossed() {
+        let mut interpolator = interpolator(table_positive());
+
+        let expectations = [
+            Transaction::read(0, 614),
+            Transaction::read(0, 819),
+            Transaction::read(0, 0),
+            Transaction::read(0, 1228),
+            Transaction::read(0, 819),
+        ];
+        let mut adc = adc(&expectations);
+
+        interpolator.read(&mut adc).unwrap();
+        assert!(!interpolator.crossed(20));
+
+        interpolator.read(&mut adc).unwrap();
+        assert!(!interpolator.crossed(20));
+        assert!(interpolator.crossed(21));
+        assert!(interpolator.crossed(30));
+        assert!(!interpolator.crossed(31));
+
+        // Readings outside the table are ignored
+        interpolator.read(&mut adc).unwrap();
+        assert!(interpolator.crossed(30));
+
+        interpolator.read(&mut adc).unwrap();
+        interpolator.read(&mut adc).unwrap();
+        assert!(interpolator.crossed(30));
+        assert!(interpolator.crossed(39));
+        assert!(!interpolator.crossed(40));
     }
 
     #[test]
-    fn matching_exact_values() {
-        assert_read_ok(table_negative(), 409, Some(40));
-        assert_read_ok(table_negative(), 819, Some(30));
-        assert_read_ok(table_negative(), 1228, Some(10));
+    fn lookup_fn() {
+        let interpolator = interpolator(table_positive());
+        let lookup = interpolator.lookup_fn();
+
+        let values: Vec<_> = [100, 409, 614, 1228, 2000]
+            .into_iter()
+            .map(lookup)
+            .collect();
+        assert_eq!(values, [None, Some(10), Some(20), Some(40), None]);
     }
 
     #[test]
-    fn interpolates() {
-        assert_read_ok(table_negative(), 502, Some(38));
-        assert_read_ok(table_negative(), 614, Some(35));
-        assert_read_ok(table_negative(), 1023, Some(21));
+    fn code_thresholds() {
+        assert_eq!(
+            interpolator(table_positive()).code_thresholds(),
+            [409, 819, 1228]
+        );
+
+        let mut interpolator = interpolator(table_positive());
+        interpolator.set_point(1, 250, 30).unwrap();
+        assert_eq!(interpolator.code_thresholds(), [409, 1024, 1228]);
     }
 
     #[test]
-    fn outside_range() {
-        assert_read_ok(table_negative(), 0, None);
-        assert_read_ok(table_negative(), 408, None);
-        assert_read_ok(table_negative(), 1229, None);
-        assert_read_ok(table_negative(), 10000, None);
+    fn sample_curve() {
+        let interpolator = interpolator(table_positive());
+
+        assert_eq!(interpolator.sample_curve::<0>(), []);
+        assert_eq!(interpolator.sample_curve::<1>(), [(409, 10)]);
+        assert_eq!(interpolator.sample_curve::<2>(), [(409, 10), (1228, 40)]);
+        assert_eq!(
+            interpolator.sample_curve::<3>(),
+            [(409, 10), (818, 29), (1228, 40)]
+        );
     }
 
     #[test]
-    fn error() {
-        let mut adc =
-            adc(&[Transaction::read(0, 0).with_error(MockError::Io(ErrorKind::InvalidData))]);
-        assert!(interpolator(table_positive()).read(&mut adc).is_err());
+    fn sample_curve_signed_words() {
+        let interpolator: AdcInterpolator<_, i16, 3> =
+            AdcInterpolator::new(MockChan0 {}, table_positive());
+
+        assert_eq!(
+            interpolator.sample_curve::<3>(),
+            [(409, 10), (818, 29), (1228, 40)]
+        );
+
+        let interpolator: AdcInterpolator<_, i16, 2> = AdcInterpolator::new(
+            MockChan0 {},
+            Config {
+                max_voltage: 1000,
+                precision: 15,
+                voltage_to_values: [(0, 0), (999, 100)],
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            interpolator.sample_curve::<3>(),
+            [(0, 0), (16367, 49), (32735, 100)]
+        );
+    }
+
+    #[test]
+    fn sample_curve_in_voltage() {
+        let interpolator = interpolator(Config {
+            interpolate_in_voltage: true,
+            ..table_positive()
+        });
+
+        assert_eq!(
+            interpolator.sample_curve::<3>(),
+            [(409, 10), (818, 29), (1228, 39)]
+        );
     }
 
     #[test]
     fn min_value() {
         assert_eq!(interpolator(table_positive()).min_value(), 10);
         assert_eq!(interpolator(table_negative()).min_value(), 10);
+        assert_eq!(interpolator(table_peak()).min_value(), 10);
     }
 
     #[test]
     fn max_value() {
         assert_eq!(interpolator(table_positive()).max_value(), 40);
         assert_eq!(interpolator(table_negative()).max_value(), 40);
+        assert_eq!(interpolator(table_peak()).max_value(), 50);
+    }
+
+    #[test]
+    fn value_range() {
+        assert_eq!(interpolator(table_positive()).value_range(), (10, 40));
+        assert_eq!(interpolator(table_negative()).value_range(), (10, 40));
+        assert_eq!(interpolator(table_peak()).value_range(), (10, 50));
+    }
+
+    #[test]
+    fn coverage_percent() {
+        assert_eq!(interpolator(table_positive()).coverage_percent(), 20);
+
+        let config = Config {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [(0, 0), (1000, 100)],
+            ..Default::default()
+        };
+        let (pin, codes) = (MockChan0 {}, [0, 4095]);
+        let full: AdcInterpolator<_, u16, 2> = AdcInterpolator::from_codes(pin, config, codes);
+        assert_eq!(full.coverage_percent(), 100);
+    }
+
+    #[test]
+    fn min_max_value_shortest_table() {
+        let shortest = interpolator(Config {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [(100, 40), (300, 10)],
+            ..Default::default()
+        });
+
+        assert_eq!(shortest.min_value(), 10);
+        assert_eq!(shortest.max_value(), 40);
     }
 }
@@ -0,0 +1,183 @@
+use crate::table::TableRef;
+use embedded_hal::adc::{Channel, OneShot};
+
+/// An interpolator which borrows its [`Table`](crate::Table) rather
+/// than owning it, so that one table can be shared by several sensors
+/// with the same calibration on different pins.
+///
+/// No state is kept between readings, so only the pin and a reference
+/// to the table are stored.
+#[derive(Debug)]
+pub struct AdcInterpolatorRef<'a, Pin, Word, const LENGTH: usize, V = u32> {
+    pin: Pin,
+    table: TableRef<'a, Word, LENGTH, V>,
+}
+
+type Error<Adc, ADC, Word, Pin> = nb::Error<<Adc as OneShot<ADC, Word, Pin>>::Error>;
+
+impl<'a, Pin, Word, const LENGTH: usize, V> AdcInterpolatorRef<'a, Pin, Word, LENGTH, V> {
+    /// Returns an interpolator reading from `pin`, using `table`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolatorRef, Config, Table};
+    /// # use embedded_hal_mock::adc::{MockChan0, MockChan1};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let table: Table<u16, 3> = Table::new(config);
+    ///
+    /// let first = AdcInterpolatorRef::new(MockChan0 {}, &table);
+    /// let second = AdcInterpolatorRef::new(MockChan1 {}, &table);
+    /// ```
+    pub fn new<ADC>(pin: Pin, table: TableRef<'a, Word, LENGTH, V>) -> Self
+    where
+        Pin: Channel<ADC>,
+    {
+        Self { pin, table }
+    }
+
+    /// Destroys the interpolator and returns the `Pin`.
+    pub fn free(self) -> Pin {
+        self.pin
+    }
+
+    /// Returns a value based on the table, as
+    /// [`AdcInterpolator::read`](crate::AdcInterpolator::read) does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{AdcInterpolatorRef, Config, Table};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, MockChan1, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 2] = [
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(1, 1228),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let table = Table::new(config);
+    ///
+    /// let mut first = AdcInterpolatorRef::new(MockChan0 {}, &table);
+    /// let mut second = AdcInterpolatorRef::new(MockChan1 {}, &table);
+    ///
+    /// // With voltage at 150 mV, the value is 35
+    /// assert_eq!(first.read(&mut adc), Ok(Some(35)));
+    /// // With voltage at 300 mV, the value is 10
+    /// assert_eq!(second.read(&mut adc), Ok(Some(10)));
+    /// ```
+    pub fn read<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+    ) -> Result<Option<u32>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let adc_value = adc.read(&mut self.pin)?;
+
+        Ok(self.process_code(adc_value))
+    }
+
+    /// Returns a value based on the table for an ADC code which has
+    /// already been sampled, as
+    /// [`AdcInterpolator::process_code`](crate::AdcInterpolator::process_code)
+    /// does.
+    pub fn process_code(&self, code: Word) -> Option<u32>
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        self.table.process(code.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::Config, table::Table};
+    use embedded_hal_mock::{
+        adc::{Mock, MockChan0, MockChan1, Transaction},
+        MockError,
+    };
+    use std::io::ErrorKind;
+
+    fn table() -> Table<u16, 3> {
+        Table::new(Config {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [(100, 10), (200, 30), (300, 40)],
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    #[should_panic]
+    fn table_panics_if_unsorted() {
+        Table::<u16, 2>::new(Config::from([(300, 30), (100, 10)]));
+    }
+
+    #[test]
+    fn shares_table() {
+        let table = table();
+        let mut first = AdcInterpolatorRef::new(MockChan0 {}, &table);
+        let mut second = AdcInterpolatorRef::new(MockChan1 {}, &table);
+
+        let expectations = [
+            Transaction::read(0, 409),
+            Transaction::read(1, 819),
+            Transaction::read(0, 1228),
+            Transaction::read(1, 1229),
+        ];
+        let mut adc = Mock::new(&expectations);
+
+        assert_eq!(first.read(&mut adc), Ok(Some(10)));
+        assert_eq!(second.read(&mut adc), Ok(Some(30)));
+        assert_eq!(first.read(&mut adc), Ok(Some(40)));
+        assert_eq!(second.read(&mut adc), Ok(None));
+        assert_eq!(first.process_code(614), Some(20));
+
+        adc.done();
+    }
+
+    #[test]
+    fn error() {
+        let table = table();
+        let mut interpolator = AdcInterpolatorRef::new(MockChan0 {}, &table);
+
+        let expectations =
+            [Transaction::read(0, 0).with_error(MockError::Io(ErrorKind::InvalidData))];
+        let mut adc = Mock::new(&expectations);
+
+        assert!(interpolator.read(&mut adc).is_err());
+        adc.done();
+    }
+}
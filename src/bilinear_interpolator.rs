@@ -0,0 +1,411 @@
+use crate::{
+    config::voltage_to_code,
+    error::ConfigError,
+    interpolate::{interpolate, RoundingMode},
+};
+use embedded_hal::adc::{Channel, OneShot};
+
+/// Configuration for a `BilinearInterpolator`.
+///
+/// - `max_voltage`: The voltage corresponding to the largest value possible for the ADC (mV)
+/// - `precision`: The precision of the ADC in bits (eg. for 10-bit precision, use `10`)
+/// - `x_voltages`: The voltages (mV) on the first pin at which values are given
+/// - `y_voltages`: The voltages (mV) on the second pin at which values are given
+/// - `values`: A grid of values, where `values[y][x]` is the value at `(x_voltages[x], y_voltages[y])`
+///
+/// # Examples
+///
+/// ```
+/// use adc_interpolator::BilinearConfig;
+///
+/// let config = BilinearConfig {
+///     max_voltage: 3300,
+///     precision: 12,
+///     x_voltages: [100, 500, 2000],
+///     y_voltages: [200, 1000],
+///     values: [
+///         [10, 20, 30], // at 200 mV on the second pin
+///         [15, 25, 35], // at 1000 mV on the second pin
+///     ],
+/// };
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct BilinearConfig<const X: usize, const Y: usize> {
+    pub max_voltage: u32,
+    pub precision: u32,
+    pub x_voltages: [u32; X],
+    pub y_voltages: [u32; Y],
+    pub values: [[u32; X]; Y],
+}
+
+impl<const X: usize, const Y: usize> BilinearConfig<X, Y> {
+    /// Returns the ADC codes for `voltages`, or an error if they
+    /// aren't in strictly ascending order, or if the code for one of
+    /// them doesn't fit in a `Word`.
+    fn codes<Word, const N: usize>(&self, voltages: [u32; N]) -> Result<[Word; N], ConfigError>
+    where
+        Word: Copy + TryFrom<u32>,
+    {
+        if self.precision >= u32::BITS {
+            return Err(ConfigError::InvalidPrecision {
+                precision: self.precision,
+            });
+        }
+
+        let codes =
+            voltages.map(|voltage| voltage_to_code(voltage, self.max_voltage, self.precision));
+
+        // the codes are checked rather than the voltages, since close
+        // voltages may round to the same code
+        if let Some(index) = (1..N).find(|index| codes[*index] <= codes[*index - 1]) {
+            return Err(ConfigError::NotAscending { index });
+        }
+
+        let codes: [Option<Word>; N] = codes.map(|code| code.try_into().ok());
+
+        if let Some(index) = codes.iter().position(Option::is_none) {
+            return Err(ConfigError::CodeOutOfRange { index });
+        }
+
+        Ok(codes.map(Option::unwrap))
+    }
+}
+
+/// An interpolator for sensors which need a two-dimensional lookup
+/// on readings from two pins, such as a pressure sensor with
+/// temperature compensation.
+#[derive(Debug)]
+pub struct BilinearInterpolator<PinX, PinY, Word, const X: usize, const Y: usize> {
+    pin_x: PinX,
+    pin_y: PinY,
+    x_codes: [Word; X],
+    y_codes: [Word; Y],
+    values: [[u32; X]; Y],
+}
+
+type Error<Adc, ADC, Word, Pin> = nb::Error<<Adc as OneShot<ADC, Word, Pin>>::Error>;
+
+impl<PinX, PinY, Word, const X: usize, const Y: usize>
+    BilinearInterpolator<PinX, PinY, Word, X, Y>
+{
+    /// Returns an interpolator reading from `pin_x` and `pin_y`,
+    /// using the provided `config`.
+    ///
+    /// Panics if `config` is invalid, as described for
+    /// [`try_new`](BilinearInterpolator::try_new).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{BilinearConfig, BilinearInterpolator};
+    /// # use embedded_hal_mock::adc::{MockChan0, MockChan1};
+    /// #
+    /// # let pin_x = MockChan0 {};
+    /// # let pin_y = MockChan1 {};
+    ///
+    /// let config = BilinearConfig {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     x_voltages: [100, 300],
+    ///     y_voltages: [100, 300],
+    ///     values: [[0, 100], [100, 200]],
+    /// };
+    ///
+    /// let interpolator = BilinearInterpolator::new(pin_x, pin_y, config);
+    /// # let interpolator_u16: BilinearInterpolator<MockChan0, MockChan1, u16, 2, 2> = interpolator;
+    /// ```
+    pub fn new<ADC>(pin_x: PinX, pin_y: PinY, config: BilinearConfig<X, Y>) -> Self
+    where
+        Word: Copy + TryFrom<u32>,
+        PinX: Channel<ADC>,
+        PinY: Channel<ADC>,
+    {
+        Self::try_new(pin_x, pin_y, config).unwrap()
+    }
+
+    /// Returns an interpolator reading from `pin_x` and `pin_y`,
+    /// using the provided `config`, or an error if `config` is
+    /// invalid.
+    ///
+    /// `config`'s `precision` must be less than 32 bits, and the ADC
+    /// codes for the voltages in its `x_voltages` and `y_voltages`
+    /// fields must be in strictly ascending order and fit in a `Word`.
+    /// The `index` of a [`ConfigError`] is the index of the offending
+    /// voltage in `x_voltages` or `y_voltages`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{BilinearConfig, BilinearInterpolator, ConfigError};
+    /// # use embedded_hal_mock::adc::{MockChan0, MockChan1};
+    /// #
+    /// # let pin_x = MockChan0 {};
+    /// # let pin_y = MockChan1 {};
+    ///
+    /// let config = BilinearConfig {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     x_voltages: [100, 300],
+    ///     y_voltages: [300, 100],
+    ///     values: [[0, 100], [100, 200]],
+    /// };
+    ///
+    /// let result: Result<BilinearInterpolator<_, _, u16, 2, 2>, _> =
+    ///     BilinearInterpolator::try_new(pin_x, pin_y, config);
+    ///
+    /// assert_eq!(result.unwrap_err(), ConfigError::NotAscending { index: 1 });
+    /// ```
+    pub fn try_new<ADC>(
+        pin_x: PinX,
+        pin_y: PinY,
+        config: BilinearConfig<X, Y>,
+    ) -> Result<Self, ConfigError>
+    where
+        Word: Copy + TryFrom<u32>,
+        PinX: Channel<ADC>,
+        PinY: Channel<ADC>,
+    {
+        Ok(Self {
+            pin_x,
+            pin_y,
+            x_codes: config.codes(config.x_voltages)?,
+            y_codes: config.codes(config.y_voltages)?,
+            values: config.values,
+        })
+    }
+
+    /// Destroys the interpolator and returns the pins.
+    pub fn free(self) -> (PinX, PinY) {
+        (self.pin_x, self.pin_y)
+    }
+
+    /// Reads both pins and returns a value based on the grid, using
+    /// bilinear interpolation between values in the grid if
+    /// necessary. If either reading falls outside the range of the
+    /// grid, returns `Ok(None)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{BilinearConfig, BilinearInterpolator};
+    /// # use embedded_hal_mock::adc::{Mock, MockChan0, MockChan1, Transaction};
+    /// #
+    /// # let expectations: [Transaction<u16>; 2] = [
+    /// #     Transaction::read(0, 818),
+    /// #     Transaction::read(1, 409),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin_x = MockChan0 {};
+    /// # let pin_y = MockChan1 {};
+    ///
+    /// let config = BilinearConfig {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     x_voltages: [100, 300],
+    ///     y_voltages: [100, 300],
+    ///     values: [[0, 100], [100, 200]],
+    /// };
+    ///
+    /// let mut interpolator = BilinearInterpolator::new(pin_x, pin_y, config);
+    ///
+    /// // With 200 mV on the first pin and 100 mV on the second, the value is 49
+    /// assert_eq!(interpolator.read(&mut adc), Ok(Some(49)));
+    /// ```
+    pub fn read<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+    ) -> Result<Option<u32>, Error<Adc, ADC, Word, PinX>>
+    where
+        Word: Copy + Into<u32>,
+        PinX: Channel<ADC>,
+        PinY: Channel<ADC>,
+        Adc: OneShot<ADC, Word, PinX>
+            + OneShot<ADC, Word, PinY, Error = <Adc as OneShot<ADC, Word, PinX>>::Error>,
+    {
+        let x: u32 = OneShot::<ADC, Word, PinX>::read(adc, &mut self.pin_x)?.into();
+        let y: u32 = OneShot::<ADC, Word, PinY>::read(adc, &mut self.pin_y)?.into();
+
+        let (Some(i), Some(j)) = (segment(&self.x_codes, x), segment(&self.y_codes, y)) else {
+            return Ok(None);
+        };
+
+        let (x0, x1) = (self.x_codes[i].into(), self.x_codes[i + 1].into());
+        let (y0, y1) = (self.y_codes[j].into(), self.y_codes[j + 1].into());
+
+        let lower = interpolate(
+            x0,
+            x1,
+            self.values[j][i],
+            self.values[j][i + 1],
+            x,
+            RoundingMode::Truncate,
+        );
+        let upper = interpolate(
+            x0,
+            x1,
+            self.values[j + 1][i],
+            self.values[j + 1][i + 1],
+            x,
+            RoundingMode::Truncate,
+        );
+
+        Ok(Some(interpolate(
+            y0,
+            y1,
+            lower,
+            upper,
+            y,
+            RoundingMode::Truncate,
+        )))
+    }
+}
+
+fn segment<Word>(codes: &[Word], code: u32) -> Option<usize>
+where
+    Word: Copy + Into<u32>,
+{
+    codes
+        .windows(2)
+        .position(|w| w[0].into() < w[1].into() && code >= w[0].into() && code <= w[1].into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_hal_mock::{
+        adc::{Mock, MockChan0, MockChan1, Transaction},
+        MockError,
+    };
+    use std::io::ErrorKind;
+
+    fn config() -> BilinearConfig<3, 2> {
+        BilinearConfig {
+            max_voltage: 1000,
+            precision: 12,
+            x_voltages: [100, 200, 300],
+            y_voltages: [100, 300],
+            values: [[0, 100, 200], [100, 200, 400]],
+        }
+    }
+
+    fn interpolator() -> BilinearInterpolator<MockChan0, MockChan1, u16, 3, 2> {
+        BilinearInterpolator::new(MockChan0 {}, MockChan1 {}, config())
+    }
+
+    fn assert_read_ok(x: u16, y: u16, expected: Option<u32>) {
+        let expectations = [Transaction::read(0, x), Transaction::read(1, y)];
+        let mut adc = Mock::new(&expectations);
+
+        assert_eq!(interpolator().read(&mut adc), Ok(expected));
+        adc.done();
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_if_unsorted() {
+        BilinearInterpolator::<_, _, u16, 3, 2>::new(
+            MockChan0 {},
+            MockChan1 {},
+            BilinearConfig {
+                x_voltages: [300, 200, 100],
+                ..config()
+            },
+        );
+    }
+
+    #[test]
+    fn try_new_errors() {
+        let try_new = |config| {
+            BilinearInterpolator::<_, _, u8, 3, 2>::try_new(MockChan0 {}, MockChan1 {}, config)
+                .map(|_| ())
+        };
+
+        assert_eq!(
+            try_new(BilinearConfig {
+                x_voltages: [100, 100, 300],
+                precision: 8,
+                ..config()
+            }),
+            Err(ConfigError::NotAscending { index: 1 })
+        );
+        // distinct voltages with the same code
+        assert_eq!(
+            try_new(BilinearConfig {
+                y_voltages: [100, 101],
+                precision: 8,
+                ..config()
+            }),
+            Err(ConfigError::NotAscending { index: 1 })
+        );
+        assert_eq!(
+            try_new(BilinearConfig {
+                x_voltages: [100, 200, 1000],
+                precision: 8,
+                ..config()
+            }),
+            Err(ConfigError::CodeOutOfRange { index: 2 })
+        );
+        assert_eq!(
+            try_new(BilinearConfig {
+                precision: 32,
+                ..config()
+            }),
+            Err(ConfigError::InvalidPrecision { precision: 32 })
+        );
+        assert_eq!(
+            try_new(BilinearConfig {
+                precision: 8,
+                ..config()
+            }),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn zero_width_segment() {
+        let mut interpolator = interpolator();
+        interpolator.x_codes = [409, 409, 1228];
+
+        let expectations = [Transaction::read(0, 409), Transaction::read(1, 409)];
+        let mut adc = Mock::new(&expectations);
+
+        assert_eq!(interpolator.read(&mut adc), Ok(Some(100)));
+        adc.done();
+    }
+
+    #[test]
+    fn matching_exact_values() {
+        assert_read_ok(409, 409, Some(0));
+        assert_read_ok(819, 409, Some(100));
+        assert_read_ok(1228, 1228, Some(400));
+        assert_read_ok(409, 1228, Some(100));
+    }
+
+    #[test]
+    fn interpolates() {
+        assert_read_ok(614, 409, Some(50));
+        assert_read_ok(409, 818, Some(49));
+        assert_read_ok(1023, 818, Some(223));
+    }
+
+    #[test]
+    fn outside_range() {
+        assert_read_ok(408, 409, None);
+        assert_read_ok(409, 1229, None);
+        assert_read_ok(0, 0, None);
+    }
+
+    #[test]
+    fn error() {
+        let expectations =
+            [Transaction::read(0, 0).with_error(MockError::Io(ErrorKind::InvalidData))];
+        let mut adc = Mock::new(&expectations);
+
+        assert!(interpolator().read(&mut adc).is_err());
+    }
+
+    #[test]
+    fn free() {
+        let (MockChan0 {}, MockChan1 {}) = interpolator().free();
+    }
+}
@@ -0,0 +1,1164 @@
+#[cfg(feature = "units")]
+use crate::units::Millivolts;
+use crate::{
+    error::ConfigError,
+    interpolate::{Boundary, Interpolation, RoundingMode},
+};
+use core::ops::Range;
+
+/// Configuration for an `AdcInterpolator` with `u32` values.
+///
+/// This is an alias of [`ValueConfig`], which can be used directly
+/// for values of other types.
+///
+/// - `max_voltage`: The voltage corresponding to the largest value possible for the ADC (mV)
+/// - `precision`: The precision of the ADC in bits (eg. for 10-bit precision, use `10`)
+/// - `voltage_to_values`: An array of tuples of `(voltage in mV, value)` which will be used for the interpolation
+/// - `interpolation`: How values between the points in `voltage_to_values` are computed (see [`Interpolation`])
+/// - `segment_interpolation`: An optional table of modes for individual segments, overriding `interpolation`, where the entry at `n` is used for readings between points `n` and `n + 1`. Segments without an entry use `interpolation`. This allows eg. a linear region of a curve to be followed by a plateau using [`Interpolation::Floor`].
+/// - `interpolate_in_voltage`: Whether readings are looked up in the table by voltage (µV) rather than by ADC code. Interpolating by voltage uses the exact voltages of the points, rather than the ADC codes they round to, which is more accurate for ADCs with few bits of precision.
+/// - `rounding`: How interpolated values which fall between two integers are rounded (see [`RoundingMode`])
+/// - `boundary`: Which segment is used for a reading which falls exactly on a point shared by two segments (see [`Boundary`])
+/// - `min_code_spacing`: The minimum difference between the ADC codes of adjacent points, enforced by [`AdcInterpolator::try_new`](crate::AdcInterpolator::try_new). Points which are too close together for the ADC's resolution make interpolation between them meaningless.
+/// - `plausible_range`: An optional inclusive range of `(lowest, highest)` raw ADC codes which the sensor can physically produce. Codes outside it indicate a fault, such as broken wiring, and are reported as [`Reading::Implausible`](crate::Reading::Implausible) by [`read_detailed`](crate::AdcInterpolator::read_detailed), and as `None` by [`read`](crate::AdcInterpolator::read).
+/// - `rail_is_error`: Whether raw ADC codes of exactly `0` or full scale, which usually indicate a shorted or open input, are treated as faults. They are reported as [`ReadError::Railed`](crate::ReadError::Railed) by [`read_required`](crate::AdcInterpolator::read_required), and as `None` by [`read`](crate::AdcInterpolator::read), rather than being looked up in the table.
+/// - `stuck_after`: An optional number of consecutive readings of exactly the same raw ADC code after which the ADC is considered to be stuck, and [`read_required`](crate::AdcInterpolator::read_required) returns [`ReadError::Stuck`](crate::ReadError::Stuck). It must be at least `2`, since a single reading can't repeat, and smaller numbers are rejected by [`AdcInterpolator::try_new`](crate::AdcInterpolator::try_new). This is only meaningful for inputs which are expected to vary, eg. because they always have some noise.
+/// - `clamp_output`: Whether values are clamped to the range of the values of the points in `voltage_to_values`, which is the range from [`min_value`](crate::AdcInterpolator::min_value) to [`max_value`](crate::AdcInterpolator::max_value). This guarantees that modes which may overshoot, such as [`Interpolation::CatmullRom`], and values given by `out_of_range_low` and `out_of_range_high`, never give values outside the range of the table.
+/// - `out_of_range_low`: What [`read`](crate::AdcInterpolator::read) returns for readings below the range of the table (see [`OutOfRange`])
+/// - `out_of_range_high`: What [`read`](crate::AdcInterpolator::read) returns for readings above the range of the table (see [`OutOfRange`])
+/// - `clamp_margin`: A number of ADC codes beyond each end of the table for which [`read`](crate::AdcInterpolator::read) returns the value of the nearest end, so that slight drift out of range is tolerated. Readings beyond the margin are handled according to `out_of_range_low` and `out_of_range_high`.
+/// - `inl_correction`: An optional table of `(ADC code, correction)`, in ascending order by code, used to correct the ADC's integral nonlinearity. The correction for a code is linearly interpolated from the table and added to the code before it is looked up in `voltage_to_values`.
+///
+/// # Examples
+///
+/// ```
+/// use adc_interpolator::Config;
+///
+/// let config = Config {
+///     max_voltage: 3300, // 3.3 V
+///     precision: 10,     // 10 bits of precision
+///     voltage_to_values: [
+///         (100, 5),   // 100 mV  -> 5
+///         (500, 10),  // 500 mV  -> 10
+///         (2000, 15), // 2000 mV -> 15
+///     ],
+///     ..Default::default()
+/// };
+/// ```
+pub type Config<const LENGTH: usize> = ValueConfig<LENGTH, u32>;
+
+/// What an [`AdcInterpolator`](crate::AdcInterpolator) returns for
+/// readings which fall outside the range of its table.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutOfRange {
+    /// Returns `None`.
+    #[default]
+    None,
+    /// Returns the value of the nearest end of the table.
+    Clamp,
+    /// Returns the provided value.
+    Value(u32),
+}
+
+impl OutOfRange {
+    fn map(self, f: impl FnOnce(u32) -> u32) -> Self {
+        match self {
+            Self::Value(value) => Self::Value(f(value)),
+            other => other,
+        }
+    }
+}
+
+/// Configuration for an `AdcInterpolator` with values of type `V`.
+///
+/// The fields are the same as [`Config`]'s. Any `Copy` type can be
+/// used for values, but only those which can be converted into a
+/// `u32` can be interpolated using
+/// [`read`](crate::AdcInterpolator::read). Other types, such as enums,
+/// can be read using
+/// [`read_value`](crate::AdcInterpolator::read_value).
+///
+/// Values which always fit in a narrower type, such as `u16` or `u8`,
+/// can be stored as that type. They are widened into a `u32` for
+/// interpolation, so the results are the same as for a [`Config`].
+/// An interpolator stores the values separately from the voltages of
+/// the points, so narrower values make it smaller.
+///
+/// # Examples
+///
+/// ```
+/// use adc_interpolator::ValueConfig;
+///
+/// let config: ValueConfig<3, u16> = ValueConfig {
+///     max_voltage: 3300,
+///     precision: 12,
+///     voltage_to_values: [(100, 4000), (500, 12_000), (2000, 65_000)],
+///     ..Default::default()
+/// };
+/// ```
+///
+/// ```
+/// use adc_interpolator::{Interpolation, ValueConfig};
+///
+/// #[derive(Clone, Copy, Default)]
+/// enum Level {
+///     #[default]
+///     Low,
+///     Medium,
+///     High,
+/// }
+///
+/// let config = ValueConfig {
+///     max_voltage: 3300,
+///     precision: 10,
+///     voltage_to_values: [
+///         (100, Level::Low),
+///         (500, Level::Medium),
+///         (2000, Level::High),
+///     ],
+///     interpolation: Interpolation::Floor,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ValueConfig<const LENGTH: usize, V> {
+    pub max_voltage: u32,
+    pub precision: u32,
+    pub voltage_to_values: [(u32, V); LENGTH],
+    pub interpolation: Interpolation,
+    pub segment_interpolation: &'static [Interpolation],
+    pub rounding: RoundingMode,
+    pub boundary: Boundary,
+    pub interpolate_in_voltage: bool,
+    pub min_code_spacing: u32,
+    pub clamp_output: bool,
+    pub out_of_range_low: OutOfRange,
+    pub out_of_range_high: OutOfRange,
+    pub clamp_margin: u32,
+    pub inl_correction: &'static [(u32, i16)],
+    pub plausible_range: Option<(u32, u32)>,
+    pub rail_is_error: bool,
+    pub stuck_after: Option<u32>,
+}
+
+impl<const LENGTH: usize, V> Default for ValueConfig<LENGTH, V>
+where
+    V: Copy + Default,
+{
+    /// Returns a configuration for a 3.3 V, 12-bit ADC using linear
+    /// interpolation, with every point in `voltage_to_values` set to
+    /// `(0, V::default())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::Config;
+    ///
+    /// let config = Config {
+    ///     precision: 10,
+    ///     ..Config::<3>::default()
+    /// };
+    ///
+    /// assert_eq!(config.max_voltage, 3300);
+    /// assert_eq!(config.voltage_to_values, [(0, 0); 3]);
+    /// ```
+    fn default() -> Self {
+        [(0, V::default()); LENGTH].into()
+    }
+}
+
+impl<const LENGTH: usize, V> From<[(u32, V); LENGTH]> for ValueConfig<LENGTH, V> {
+    /// Returns a configuration using `voltage_to_values`, with the
+    /// other fields set as in [`Default`](ValueConfig::default).
+    ///
+    /// Combined with [`with_max_voltage`](ValueConfig::with_max_voltage)
+    /// and [`with_precision`](ValueConfig::with_precision), this allows
+    /// the length of the configuration to be inferred from an array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::Config;
+    ///
+    /// let config = Config::from([(100, 5), (500, 10), (2000, 15)])
+    ///     .with_max_voltage(5000)
+    ///     .with_precision(10);
+    ///
+    /// assert_eq!(config.max_voltage, 5000);
+    /// assert_eq!(config.precision, 10);
+    /// ```
+    fn from(voltage_to_values: [(u32, V); LENGTH]) -> Self {
+        Self {
+            max_voltage: 3300,
+            precision: 12,
+            voltage_to_values,
+            interpolation: Interpolation::default(),
+            segment_interpolation: &[],
+            rounding: RoundingMode::default(),
+            boundary: Boundary::default(),
+            interpolate_in_voltage: false,
+            min_code_spacing: 0,
+            clamp_output: false,
+            out_of_range_low: OutOfRange::default(),
+            out_of_range_high: OutOfRange::default(),
+            clamp_margin: 0,
+            inl_correction: &[],
+            plausible_range: None,
+            rail_is_error: false,
+            stuck_after: None,
+        }
+    }
+}
+
+impl<const LENGTH: usize, V> ValueConfig<LENGTH, V> {
+    /// Returns the configuration with `max_voltage` (mV) replaced.
+    pub fn with_max_voltage(self, max_voltage: u32) -> Self {
+        Self {
+            max_voltage,
+            ..self
+        }
+    }
+
+    /// Returns the configuration with `precision` (bits) replaced.
+    pub fn with_precision(self, precision: u32) -> Self {
+        Self { precision, ..self }
+    }
+
+    /// Returns the configuration with both `out_of_range_low` and
+    /// `out_of_range_high` replaced by `out_of_range`.
+    pub fn with_out_of_range(self, out_of_range: OutOfRange) -> Self {
+        Self {
+            out_of_range_low: out_of_range,
+            out_of_range_high: out_of_range,
+            ..self
+        }
+    }
+
+    /// Returns a configuration using `max_voltage` and
+    /// `voltage_to_values`, with the other fields set as in
+    /// [`Default`](ValueConfig::default).
+    ///
+    /// Unlike setting the fields directly, the voltages must be
+    /// [`Millivolts`], so that they can't be given in volts by mistake.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{Config, Millivolts};
+    ///
+    /// let config = Config::from_millivolts(
+    ///     Millivolts(3300),
+    ///     [(Millivolts(100), 5), (Millivolts(500), 10)],
+    /// );
+    ///
+    /// assert_eq!(config.max_voltage, 3300);
+    /// assert_eq!(config.voltage_to_values, [(100, 5), (500, 10)]);
+    /// ```
+    #[cfg(feature = "units")]
+    pub fn from_millivolts(
+        max_voltage: Millivolts,
+        voltage_to_values: [(Millivolts, V); LENGTH],
+    ) -> Self {
+        Self {
+            max_voltage: max_voltage.into(),
+            ..voltage_to_values
+                .map(|(voltage, value)| (voltage.into(), value))
+                .into()
+        }
+    }
+
+    /// Returns the voltage represented by a single ADC code as a
+    /// fraction `(numerator, denominator)` in mV, reduced to lowest
+    /// terms.
+    ///
+    /// A fraction is returned so that resolutions finer than 1 mV
+    /// per code are not truncated to zero. Returns `None` if
+    /// `precision` is 32 or more, since the number of codes doesn't
+    /// fit in a `u32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::Config;
+    ///
+    /// let config = Config {
+    ///     max_voltage: 3300,
+    ///     precision: 12,
+    ///     voltage_to_values: [(0, 0), (3300, 100)],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// // 3300 mV / 4096 codes ≈ 0.8 mV per code
+    /// assert_eq!(config.mv_per_code(), Some((825, 1024)));
+    /// ```
+    pub fn mv_per_code(&self) -> Option<(u32, u32)> {
+        self.check_precision().ok()?;
+
+        let max_adc_value = self.max_adc_value();
+        let divisor = gcd(self.max_voltage, max_adc_value);
+
+        Some((self.max_voltage / divisor, max_adc_value / divisor))
+    }
+
+    fn max_adc_value(&self) -> u32 {
+        max_adc_value(self.precision)
+    }
+
+    /// Returns the highest code the ADC can return.
+    pub(crate) fn full_scale_code(&self) -> u32 {
+        self.max_adc_value() - 1
+    }
+
+    /// Returns the ADC code corresponding to `voltage` (mV).
+    pub(crate) fn code(&self, voltage: u32) -> u32 {
+        voltage_to_code(voltage, self.max_voltage, self.precision)
+    }
+
+    /// Returns the voltage (mV) corresponding to the ADC code `code`.
+    pub(crate) fn voltage(&self, code: u32) -> u32 {
+        (u64::from(code) * u64::from(self.max_voltage) / u64::from(self.max_adc_value())) as u32
+    }
+
+    /// Returns the voltage (µV) corresponding to the ADC code `code`.
+    pub(crate) fn microvolts(&self, code: u32) -> u32 {
+        (u64::from(code) * u64::from(self.max_voltage) * 1000 / u64::from(self.max_adc_value()))
+            as u32
+    }
+
+    /// Returns whether the raw ADC code `code` lies within
+    /// `plausible_range`.
+    pub(crate) fn plausible(&self, code: i64) -> bool {
+        match self.plausible_range {
+            Some((lowest, highest)) => code >= lowest.into() && code <= highest.into(),
+            None => true,
+        }
+    }
+
+    /// Returns whether the raw ADC code `code` is `0` or full scale,
+    /// if `rail_is_error` is set.
+    pub(crate) fn railed(&self, code: i64) -> bool {
+        self.rail_is_error && (code == 0 || code == self.full_scale_code().into())
+    }
+
+    /// Returns `code` corrected using `inl_correction`, clamped to the
+    /// range of the ADC.
+    pub(crate) fn correct(&self, code: u32) -> u32 {
+        let table = self.inl_correction;
+
+        let correction = match table.iter().position(|(x, _)| code < *x) {
+            None => match table.last() {
+                Some((_, correction)) => i64::from(*correction),
+                None => return code,
+            },
+            Some(0) => i64::from(table[0].1),
+            Some(index) => {
+                let (x0, c0) = table[index - 1];
+                let (x1, c1) = table[index];
+
+                i64::from(c0)
+                    + i64::from(code - x0) * (i64::from(c1) - i64::from(c0)) / i64::from(x1 - x0)
+            }
+        };
+
+        (i64::from(code) + correction).clamp(0, i64::from(self.full_scale_code())) as u32
+    }
+
+    /// Returns an error if `precision` isn't less than 32 bits.
+    pub(crate) fn check_precision(&self) -> Result<(), ConfigError> {
+        if self.precision >= u32::BITS {
+            return Err(ConfigError::InvalidPrecision {
+                precision: self.precision,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns an error if `precision` is too high, if the points in
+    /// `voltage_to_values` or `inl_correction` are not in strictly
+    /// ascending order, if adjacent points are closer together than
+    /// `min_code_spacing`, or if `stuck_after` is less than `2`.
+    ///
+    /// Unless `interpolate_in_voltage` is set, adjacent points must
+    /// also have different ADC codes, so that no segment of the table
+    /// has zero width.
+    pub(crate) fn validate(&self) -> Result<(), ConfigError> {
+        self.check_precision()?;
+        self.check_segments(1..LENGTH)?;
+        self.check_inl_correction()?;
+        self.check_stuck_after()
+    }
+
+    /// Returns an error if the segments ending at the points in
+    /// `segments` aren't valid, as [`validate`](ValueConfig::validate)
+    /// checks for every segment of the table.
+    pub(crate) fn check_segments(&self, segments: Range<usize>) -> Result<(), ConfigError> {
+        if let Some(index) = segments
+            .clone()
+            .find(|&index| self.voltage_to_values[index - 1].0 >= self.voltage_to_values[index].0)
+        {
+            return Err(ConfigError::NotAscending { index });
+        }
+
+        self.check_code_spacing(segments, |index| self.code(self.voltage_to_values[index].0))
+    }
+
+    /// Returns an error if the ADC codes of adjacent points in the
+    /// segments ending at `segments`, given by `code`, which must be
+    /// in ascending order, are closer together than
+    /// `min_code_spacing`, or are the same unless
+    /// `interpolate_in_voltage` is set.
+    pub(crate) fn check_code_spacing(
+        &self,
+        mut segments: Range<usize>,
+        code: impl Fn(usize) -> u32,
+    ) -> Result<(), ConfigError> {
+        let min_code_spacing = if self.interpolate_in_voltage {
+            self.min_code_spacing
+        } else {
+            self.min_code_spacing.max(1)
+        };
+
+        match segments.find(|&index| code(index) - code(index - 1) < min_code_spacing) {
+            Some(index) => Err(ConfigError::PointsTooClose { index }),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns an error if the entries in `inl_correction` are not in
+    /// strictly ascending order by code.
+    pub(crate) fn check_inl_correction(&self) -> Result<(), ConfigError> {
+        match (1..self.inl_correction.len())
+            .find(|&index| self.inl_correction[index - 1].0 >= self.inl_correction[index].0)
+        {
+            Some(index) => Err(ConfigError::InlCorrectionNotAscending { index }),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns an error if `stuck_after` is set to less than `2`, which
+    /// would report every reading as stuck.
+    pub(crate) fn check_stuck_after(&self) -> Result<(), ConfigError> {
+        match self.stuck_after {
+            Some(stuck_after) if stuck_after < 2 => {
+                Err(ConfigError::InvalidStuckAfter { stuck_after })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the ADC code for each point in `voltage_to_values`, or
+    /// an error if the full-scale code or any of the codes don't fit
+    /// in a `Word`.
+    pub(crate) fn codes<Word>(&self) -> Result<[Word; LENGTH], ConfigError>
+    where
+        Word: Copy + TryFrom<u32>,
+    {
+        if Word::try_from(self.full_scale_code()).is_err() {
+            return Err(ConfigError::PrecisionExceedsWord {
+                precision: self.precision,
+            });
+        }
+
+        let codes: [Option<Word>; LENGTH] = core::array::from_fn(|index| {
+            self.code(self.voltage_to_values[index].0).try_into().ok()
+        });
+
+        if let Some(index) = codes.iter().position(Option::is_none) {
+            return Err(ConfigError::CodeOutOfRange { index });
+        }
+
+        Ok(codes.map(Option::unwrap))
+    }
+
+    /// Splits the configuration into the values of its points and a
+    /// configuration holding only their voltages, so that a table can
+    /// store values narrower than a `u32` without padding each point.
+    pub(crate) fn split(self) -> (ValueConfig<LENGTH, ()>, [V; LENGTH])
+    where
+        V: Copy,
+    {
+        let config = ValueConfig {
+            max_voltage: self.max_voltage,
+            precision: self.precision,
+            voltage_to_values: self.voltage_to_values.map(|(voltage, _)| (voltage, ())),
+            interpolation: self.interpolation,
+            segment_interpolation: self.segment_interpolation,
+            rounding: self.rounding,
+            boundary: self.boundary,
+            interpolate_in_voltage: self.interpolate_in_voltage,
+            min_code_spacing: self.min_code_spacing,
+            clamp_output: self.clamp_output,
+            out_of_range_low: self.out_of_range_low,
+            out_of_range_high: self.out_of_range_high,
+            clamp_margin: self.clamp_margin,
+            inl_correction: self.inl_correction,
+            plausible_range: self.plausible_range,
+            rail_is_error: self.rail_is_error,
+            stuck_after: self.stuck_after,
+        };
+
+        (config, self.voltage_to_values.map(|(_, value)| value))
+    }
+}
+
+impl Config<2> {
+    /// Returns a two-point configuration for the line which best fits
+    /// `samples` of `(voltage in mV, value)`, using least squares.
+    ///
+    /// The points are placed at the smallest and largest voltages in
+    /// `samples`. Fitted values below zero are clamped to zero.
+    /// Returns `None` if `samples` doesn't contain at least two
+    /// distinct voltages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::Config;
+    ///
+    /// let samples = [(100, 10), (200, 21), (300, 29), (400, 40)];
+    /// let config = Config::from_samples_linear(3300, 12, &samples).unwrap();
+    ///
+    /// assert_eq!(config.voltage_to_values, [(100, 10), (400, 40)]);
+    /// ```
+    #[cfg(feature = "float")]
+    pub fn from_samples_linear(
+        max_voltage: u32,
+        precision: u32,
+        samples: &[(u32, u32)],
+    ) -> Option<Self> {
+        let count = samples.len() as f32;
+        let (sum_x, sum_y) = samples.iter().fold((0.0, 0.0), |(sum_x, sum_y), (x, y)| {
+            (sum_x + *x as f32, sum_y + *y as f32)
+        });
+        let (mean_x, mean_y) = (sum_x / count, sum_y / count);
+
+        let (covariance, variance) =
+            samples
+                .iter()
+                .fold((0.0, 0.0), |(covariance, variance), (x, y)| {
+                    let dx = *x as f32 - mean_x;
+                    let dy = *y as f32 - mean_y;
+
+                    (covariance + dx * dy, variance + dx * dx)
+                });
+
+        if samples.len() < 2 || variance == 0.0 {
+            return None;
+        }
+
+        let slope = covariance / variance;
+        let fit = |x: u32| (mean_y + slope * (x as f32 - mean_x) + 0.5) as u32;
+
+        let min_x = samples.iter().map(|(x, _)| *x).min()?;
+        let max_x = samples.iter().map(|(x, _)| *x).max()?;
+
+        Some(Self {
+            max_voltage,
+            precision,
+            voltage_to_values: [(min_x, fit(min_x)), (max_x, fit(max_x))],
+            ..Default::default()
+        })
+    }
+}
+
+impl<const LENGTH: usize> Config<LENGTH> {
+    /// Returns a configuration for a resistive sensor, such as an NTC
+    /// thermistor, in a voltage divider with a series resistor of
+    /// `r_series` Ω, from `points` of `(sensor resistance in Ω, value)`.
+    ///
+    /// The sensor is assumed to be between the ADC input and ground,
+    /// and the series resistor between the ADC input and `vref_mv`,
+    /// which is also used as the ADC's `max_voltage`. The voltage for
+    /// each point is then `vref_mv * r / (r + r_series)`, rounded to
+    /// the nearest mV, so `points` must be in ascending order by
+    /// resistance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::Config;
+    ///
+    /// // A 10 kΩ NTC thermistor with a 10 kΩ series resistor
+    /// let config = Config::from_divider(10_000, 3300, 12, [
+    ///     (3_000, 55),
+    ///     (10_000, 25),
+    ///     (30_000, 0),
+    /// ]);
+    ///
+    /// assert_eq!(config.max_voltage, 3300);
+    /// assert_eq!(config.voltage_to_values, [(762, 55), (1650, 25), (2475, 0)]);
+    /// ```
+    pub fn from_divider(
+        r_series: u32,
+        vref_mv: u32,
+        precision: u32,
+        points: [(u32, u32); LENGTH],
+    ) -> Self {
+        let voltage = |r: u32| {
+            let total = u64::from(r) + u64::from(r_series);
+
+            if total == 0 {
+                return 0;
+            }
+
+            ((u64::from(vref_mv) * u64::from(r) + total / 2) / total) as u32
+        };
+
+        Self {
+            max_voltage: vref_mv,
+            precision,
+            ..points.map(|(r, value)| (voltage(r), value)).into()
+        }
+    }
+
+    /// Returns the configuration with every value, including any in
+    /// `out_of_range_low` and `out_of_range_high`, multiplied by
+    /// `num / den`, rounded to the nearest integer and saturating at
+    /// `u32::MAX`.
+    ///
+    /// This allows a table to be reused in different units.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `den` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::Config;
+    ///
+    /// // A table in 0.1 °C, scaled to m°C
+    /// let config = Config::from([(100, 250), (200, 300)]).scale_values(100, 1);
+    ///
+    /// assert_eq!(config.voltage_to_values, [(100, 25000), (200, 30000)]);
+    /// ```
+    pub fn scale_values(self, num: u32, den: u32) -> Config<LENGTH> {
+        assert!(den != 0, "The denominator must not be zero");
+
+        let scale = |value: u32| {
+            let scaled = (u64::from(value) * u64::from(num) + u64::from(den / 2)) / u64::from(den);
+
+            u32::try_from(scaled).unwrap_or(u32::MAX)
+        };
+
+        Self {
+            voltage_to_values: self
+                .voltage_to_values
+                .map(|(voltage, value)| (voltage, scale(value))),
+            out_of_range_low: self.out_of_range_low.map(scale),
+            out_of_range_high: self.out_of_range_high.map(scale),
+            ..self
+        }
+    }
+
+    /// Returns a configuration from a maximum voltage in V and
+    /// `points` of `(voltage in V, value)`, rounding the voltages to
+    /// the nearest mV and the values to the nearest integer.
+    ///
+    /// Negative voltages and values are clamped to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::Config;
+    ///
+    /// let config = Config::from_f32(3.3, 12, [(0.15, 35.0), (0.3, 9.6)]);
+    ///
+    /// assert_eq!(config.max_voltage, 3300);
+    /// assert_eq!(config.voltage_to_values, [(150, 35), (300, 10)]);
+    /// ```
+    #[cfg(feature = "float")]
+    pub fn from_f32(max_voltage_v: f32, precision: u32, points: [(f32, f32); LENGTH]) -> Self {
+        let round = |x: f32| (x + 0.5) as u32;
+
+        Self {
+            max_voltage: round(max_voltage_v * 1000.0),
+            precision,
+            ..points
+                .map(|(voltage, value)| (round(voltage * 1000.0), round(value)))
+                .into()
+        }
+    }
+}
+
+/// Returns the minimum precision in bits an ADC needs for one code to
+/// be no more than `min_mv_step` mV, over a range of `max_voltage` mV.
+///
+/// Returns `None` if no precision a [`Config`] supports, which is at
+/// most 31 bits, is enough, eg. if `min_mv_step` is `0`.
+///
+/// # Examples
+///
+/// ```
+/// use adc_interpolator::min_precision_for;
+///
+/// // 3300 mV / 4096 codes is ~0.8 mV per code
+/// assert_eq!(min_precision_for(3300, 1), Some(12));
+/// assert_eq!(min_precision_for(3300, 4), Some(10));
+/// assert_eq!(min_precision_for(3300, 0), None);
+/// ```
+pub fn min_precision_for(max_voltage: u32, min_mv_step: u32) -> Option<u32> {
+    if min_mv_step == 0 {
+        return None;
+    }
+
+    let steps = max_voltage.div_ceil(min_mv_step);
+    let precision = if steps <= 1 {
+        0
+    } else {
+        u32::BITS - (steps - 1).leading_zeros()
+    };
+
+    (precision < u32::BITS).then_some(precision)
+}
+
+pub(crate) fn max_adc_value(precision: u32) -> u32 {
+    2u32.pow(precision)
+}
+
+/// Returns the ADC code for `voltage`, saturating at `u32::MAX`. The
+/// code is computed in a `u64`, so that it can't overflow for wide
+/// ADCs.
+pub(crate) fn voltage_to_code(voltage: u32, max_voltage: u32, precision: u32) -> u32 {
+    let code = u64::from(voltage) * u64::from(max_adc_value(precision)) / u64::from(max_voltage);
+
+    u32::try_from(code).unwrap_or(u32::MAX)
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config<3> {
+        Config {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [(100, 10), (200, 30), (300, 40)],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn validate() {
+        assert_eq!(config().validate(), Ok(()));
+
+        let config = Config {
+            voltage_to_values: [(100, 10), (300, 30), (200, 40)],
+            ..config()
+        };
+        assert_eq!(
+            config.validate(),
+            Err(ConfigError::NotAscending { index: 2 })
+        );
+    }
+
+    #[test]
+    fn validate_duplicate_voltages() {
+        let config = Config {
+            voltage_to_values: [(100, 40), (100, 30), (300, 10)],
+            ..config()
+        };
+
+        assert_eq!(
+            config.validate(),
+            Err(ConfigError::NotAscending { index: 1 })
+        );
+    }
+
+    #[test]
+    fn validate_duplicate_codes() {
+        // 100 mV and 101 mV are both code 25 at 8 bits
+        let config = Config {
+            precision: 8,
+            voltage_to_values: [(100, 40), (101, 30), (300, 10)],
+            ..config()
+        };
+
+        assert_eq!(
+            config.validate(),
+            Err(ConfigError::PointsTooClose { index: 1 })
+        );
+        assert_eq!(
+            Config {
+                interpolate_in_voltage: true,
+                ..config
+            }
+            .validate(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_precision() {
+        assert_eq!(
+            Config {
+                precision: 32,
+                ..config()
+            }
+            .validate(),
+            Err(ConfigError::InvalidPrecision { precision: 32 })
+        );
+        assert_eq!(
+            Config {
+                precision: 31,
+                ..config()
+            }
+            .validate(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn code_does_not_overflow() {
+        let config = Config {
+            max_voltage: 3000,
+            precision: 24,
+            voltage_to_values: [(0, 0), (3000, 10)],
+            ..Default::default()
+        };
+
+        assert_eq!(config.code(1500), 8_388_608);
+        assert_eq!(config.code(3000), 16_777_216);
+    }
+
+    #[test]
+    fn validate_min_code_spacing() {
+        // 100 mV and 101 mV are 4 codes apart
+        let config = Config {
+            voltage_to_values: [(100, 10), (101, 20), (200, 30)],
+            ..config()
+        };
+
+        assert_eq!(config.validate(), Ok(()));
+        assert_eq!(
+            Config {
+                min_code_spacing: 4,
+                ..config
+            }
+            .validate(),
+            Ok(())
+        );
+        assert_eq!(
+            Config {
+                min_code_spacing: 5,
+                ..config
+            }
+            .validate(),
+            Err(ConfigError::PointsTooClose { index: 1 })
+        );
+    }
+
+    #[test]
+    fn validate_inl_correction() {
+        let config = Config {
+            inl_correction: &[(0, 1), (100, 2), (100, 3)],
+            ..config()
+        };
+
+        assert_eq!(
+            config.validate(),
+            Err(ConfigError::InlCorrectionNotAscending { index: 2 })
+        );
+    }
+
+    #[test]
+    fn validate_stuck_after() {
+        let with_stuck_after = |stuck_after| Config {
+            stuck_after,
+            ..config()
+        };
+
+        assert_eq!(
+            with_stuck_after(Some(0)).validate(),
+            Err(ConfigError::InvalidStuckAfter { stuck_after: 0 })
+        );
+        assert_eq!(
+            with_stuck_after(Some(1)).validate(),
+            Err(ConfigError::InvalidStuckAfter { stuck_after: 1 })
+        );
+        assert_eq!(with_stuck_after(Some(2)).validate(), Ok(()));
+        assert_eq!(with_stuck_after(None).validate(), Ok(()));
+    }
+
+    #[test]
+    fn correct() {
+        assert_eq!(config().correct(1234), 1234);
+
+        let config = Config {
+            inl_correction: &[(1000, 4), (2000, -4), (3000, 0)],
+            ..config()
+        };
+
+        assert_eq!(config.correct(0), 4);
+        assert_eq!(config.correct(1000), 1004);
+        assert_eq!(config.correct(1500), 1500);
+        assert_eq!(config.correct(1750), 1748);
+        assert_eq!(config.correct(2500), 2498);
+        assert_eq!(config.correct(4000), 4000);
+
+        let config = Config {
+            inl_correction: &[(0, -2), (4095, 2)],
+            ..config
+        };
+
+        assert_eq!(config.correct(1), 0);
+        assert_eq!(config.correct(4095), 4095);
+    }
+
+    #[test]
+    fn codes() {
+        assert_eq!(config().codes::<u16>(), Ok([409, 819, 1228]));
+    }
+
+    #[test]
+    fn codes_out_of_range() {
+        let config = Config {
+            max_voltage: 1000,
+            precision: 8,
+            voltage_to_values: [(0, 0), (500, 5), (1000, 10)],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            config.codes::<u8>(),
+            Err(ConfigError::CodeOutOfRange { index: 2 })
+        );
+        assert!(config.codes::<u16>().is_ok());
+    }
+
+    #[test]
+    fn codes_precision_exceeds_word() {
+        let config = Config {
+            max_voltage: 1000,
+            precision: 16,
+            voltage_to_values: [(0, 0), (1, 5)],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            config.codes::<u8>(),
+            Err(ConfigError::PrecisionExceedsWord { precision: 16 })
+        );
+        assert_eq!(
+            config.codes::<i16>(),
+            Err(ConfigError::PrecisionExceedsWord { precision: 16 })
+        );
+        assert!(config.codes::<u16>().is_ok());
+    }
+
+    #[test]
+    fn from_array() {
+        let config: Config<2> = [(100, 5), (500, 10)].into();
+
+        assert_eq!(config.max_voltage, 3300);
+        assert_eq!(config.precision, 12);
+        assert_eq!(config.voltage_to_values, [(100, 5), (500, 10)]);
+        assert_eq!(config.interpolation, Interpolation::Linear);
+        assert_eq!(config.boundary, Boundary::Lower);
+    }
+
+    #[test]
+    fn with_out_of_range() {
+        let config = Config::from([(100, 5), (500, 10)]);
+        assert_eq!(config.out_of_range_low, OutOfRange::None);
+        assert_eq!(config.out_of_range_high, OutOfRange::None);
+
+        let config = config.with_out_of_range(OutOfRange::Clamp);
+        assert_eq!(config.out_of_range_low, OutOfRange::Clamp);
+        assert_eq!(config.out_of_range_high, OutOfRange::Clamp);
+    }
+
+    #[test]
+    fn builder() {
+        let config = Config::from([(100, 5), (500, 10), (2000, 15)])
+            .with_max_voltage(1000)
+            .with_precision(10);
+
+        assert_eq!(config.max_voltage, 1000);
+        assert_eq!(config.precision, 10);
+        assert_eq!(config.voltage_to_values, [(100, 5), (500, 10), (2000, 15)]);
+    }
+
+    #[test]
+    fn scale_values() {
+        let config = Config {
+            out_of_range_low: OutOfRange::Value(7),
+            out_of_range_high: OutOfRange::Value(u32::MAX),
+            ..Config::from([(100, 5), (500, 10), (2000, 15)])
+        };
+
+        let scaled = config.scale_values(2, 3);
+        assert_eq!(scaled.voltage_to_values, [(100, 3), (500, 7), (2000, 10)]);
+        assert_eq!(scaled.out_of_range_low, OutOfRange::Value(5));
+        assert_eq!(scaled.out_of_range_high, OutOfRange::Value(2_863_311_530));
+
+        let scaled = config.scale_values(u32::MAX, 1);
+        assert_eq!(scaled.voltage_to_values[0], (100, u32::MAX));
+        assert_eq!(scaled.out_of_range_high, OutOfRange::Value(u32::MAX));
+        assert_eq!(
+            config.scale_values(1, 1).voltage_to_values,
+            config.voltage_to_values
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn scale_values_zero_denominator() {
+        Config::from([(100, 5), (500, 10)]).scale_values(1, 0);
+    }
+
+    #[test]
+    fn from_divider() {
+        let config = Config::from_divider(1_000, 5000, 10, [(0, 1), (1_000, 2), (u32::MAX, 3)]);
+
+        assert_eq!(config.max_voltage, 5000);
+        assert_eq!(config.precision, 10);
+        assert_eq!(config.voltage_to_values, [(0, 1), (2500, 2), (5000, 3)]);
+        assert_eq!(
+            Config::from_divider(0, 5000, 10, [(0, 1), (1, 2)]).voltage_to_values,
+            [(0, 1), (5000, 2)]
+        );
+    }
+
+    #[test]
+    fn voltage() {
+        assert_eq!(config().voltage(0), 0);
+        assert_eq!(config().voltage(409), 99);
+        assert_eq!(config().voltage(410), 100);
+        assert_eq!(config().voltage(4096), 1000);
+    }
+
+    #[test]
+    fn plausible() {
+        assert!(config().plausible(0));
+        assert!(config().plausible(4095));
+
+        let config = Config {
+            plausible_range: Some((100, 4000)),
+            ..config()
+        };
+
+        assert!(!config.plausible(99));
+        assert!(config.plausible(100));
+        assert!(config.plausible(4000));
+        assert!(!config.plausible(4001));
+    }
+
+    #[test]
+    fn min_precision_for() {
+        assert_eq!(super::min_precision_for(1024, 1), Some(10));
+        assert_eq!(super::min_precision_for(1025, 1), Some(11));
+        assert_eq!(super::min_precision_for(1000, 1), Some(10));
+        assert_eq!(super::min_precision_for(1000, 1000), Some(0));
+        assert_eq!(super::min_precision_for(1000, 2000), Some(0));
+        assert_eq!(super::min_precision_for(1000, 0), None);
+        assert_eq!(super::min_precision_for(1 << 31, 1), Some(31));
+        assert_eq!(super::min_precision_for((1 << 31) + 1, 1), None);
+        assert_eq!(super::min_precision_for(u32::MAX, 1), None);
+    }
+
+    #[test]
+    fn microvolts() {
+        assert_eq!(config().microvolts(0), 0);
+        assert_eq!(config().microvolts(1), 244);
+        assert_eq!(config().microvolts(409), 99853);
+        assert_eq!(config().microvolts(4096), 1_000_000);
+    }
+
+    #[test]
+    fn default_config() {
+        let config = Config {
+            voltage_to_values: [(100, 10), (200, 30)],
+            ..Default::default()
+        };
+
+        assert_eq!(config.max_voltage, 3300);
+        assert_eq!(config.precision, 12);
+        assert_eq!(config.voltage_to_values, [(100, 10), (200, 30)]);
+    }
+
+    #[test]
+    fn mv_per_code() {
+        assert_eq!(config().mv_per_code(), Some((125, 512)));
+
+        let config = Config {
+            max_voltage: 4096,
+            precision: 10,
+            ..config()
+        };
+        assert_eq!(config.mv_per_code(), Some((4, 1)));
+
+        assert_eq!(config.with_precision(31).mv_per_code(), Some((1, 524_288)));
+        assert_eq!(config.with_precision(32).mv_per_code(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn from_samples_linear() {
+        let samples = [(500, 95), (100, 205), (300, 155), (200, 170), (400, 125)];
+        let config = Config::from_samples_linear(1000, 12, &samples).unwrap();
+
+        assert_eq!(config.max_voltage, 1000);
+        assert_eq!(config.precision, 12);
+        assert_eq!(config.voltage_to_values, [(100, 203), (500, 97)]);
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn from_samples_linear_clamps_to_zero() {
+        let samples = [(100, 20), (200, 0)];
+        let config = Config::from_samples_linear(1000, 12, &samples).unwrap();
+
+        assert_eq!(config.voltage_to_values, [(100, 20), (200, 0)]);
+
+        let samples = [(100, 10), (200, 5), (300, 0), (400, 0)];
+        let config = Config::from_samples_linear(1000, 12, &samples).unwrap();
+
+        assert_eq!(config.voltage_to_values[1], (400, 0));
+    }
+
+    #[test]
+    #[cfg(feature = "units")]
+    fn from_millivolts() {
+        let config = Config::from_millivolts(
+            Millivolts(1000),
+            [(Millivolts(100), 10), (Millivolts(300), 40)],
+        );
+
+        assert_eq!(config.max_voltage, 1000);
+        assert_eq!(config.precision, 12);
+        assert_eq!(config.voltage_to_values, [(100, 10), (300, 40)]);
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn from_f32() {
+        let config = Config::from_f32(1.0, 10, [(0.1004, 0.4), (0.2005, 1.5), (0.9, 10.0)]);
+
+        assert_eq!(config.max_voltage, 1000);
+        assert_eq!(config.precision, 10);
+        assert_eq!(config.voltage_to_values, [(100, 0), (201, 2), (900, 10)]);
+        assert_eq!(config.interpolation, Interpolation::Linear);
+
+        let config = Config::from_f32(1.0, 10, [(-0.1, -5.0), (0.5, 5.0)]);
+
+        assert_eq!(config.voltage_to_values, [(0, 0), (500, 5)]);
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn from_samples_linear_invalid() {
+        assert!(Config::from_samples_linear(1000, 12, &[]).is_none());
+        assert!(Config::from_samples_linear(1000, 12, &[(100, 10)]).is_none());
+        assert!(Config::from_samples_linear(1000, 12, &[(100, 10), (100, 20)]).is_none());
+    }
+}
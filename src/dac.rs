@@ -0,0 +1,16 @@
+/// A digital-to-analog converter which values can be written to by
+/// [`AdcInterpolator::transfer`](crate::AdcInterpolator::transfer).
+///
+/// embedded-hal doesn't have a trait for DACs, so this should be
+/// implemented for the DAC driver in use.
+pub trait Dac {
+    /// Error type returned by DAC methods.
+    type Error;
+
+    /// Returns the highest code the DAC accepts, which corresponds to
+    /// its full-scale output.
+    fn max_code(&self) -> u32;
+
+    /// Sets the output of the DAC to `code`.
+    fn write(&mut self, code: u32) -> nb::Result<(), Self::Error>;
+}
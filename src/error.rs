@@ -0,0 +1,198 @@
+use core::fmt;
+
+/// An error returned when a configuration can't be used to build an
+/// interpolator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfigError {
+    /// The point at `index` doesn't have a higher voltage than the
+    /// point before it.
+    NotAscending { index: usize },
+    /// The ADC code for the point at `index` doesn't fit in the ADC's
+    /// word type, eg. because its voltage is above `max_voltage`.
+    CodeOutOfRange { index: usize },
+    /// The ADC code for the point at `index` is closer to the code for
+    /// the point before it than the configured minimum spacing, or is
+    /// the same code, which leaves nothing to interpolate between.
+    PointsTooClose { index: usize },
+    /// There is no point at `index`.
+    IndexOutOfRange { index: usize },
+    /// The entry at `index` in the INL correction table doesn't have
+    /// a higher code than the entry before it.
+    InlCorrectionNotAscending { index: usize },
+    /// A serialized table has fewer bytes than its points need.
+    TableTooShort,
+    /// The precision is too high, since it must be less than 32 bits.
+    InvalidPrecision { precision: u32 },
+    /// The ADC's full-scale code for the configured precision doesn't
+    /// fit in the ADC's word type, eg. a precision of 16 bits with a
+    /// `u8` word.
+    PrecisionExceedsWord { precision: u32 },
+    /// The value for the point at `index` doesn't fit in the table's
+    /// value type, eg. a value above `u16::MAX` for `u16` values.
+    ValueOutOfRange { index: usize },
+    /// The configuration's `stuck_after` is less than `2`, so every
+    /// reading would be reported as stuck.
+    InvalidStuckAfter { stuck_after: u32 },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotAscending { index } => {
+                write!(f, "point {} is not in ascending order by voltage", index)
+            }
+            Self::CodeOutOfRange { index } => write!(
+                f,
+                "the ADC code for point {} does not fit in the ADC's word type",
+                index
+            ),
+            Self::PointsTooClose { index } => {
+                write!(f, "point {} is too close to the point before it", index)
+            }
+            Self::IndexOutOfRange { index } => write!(f, "there is no point {}", index),
+            Self::InlCorrectionNotAscending { index } => write!(
+                f,
+                "INL correction {} is not in ascending order by code",
+                index
+            ),
+            Self::TableTooShort => write!(f, "the serialized table is too short"),
+            Self::InvalidPrecision { precision } => {
+                write!(f, "a precision of {} bits is not supported", precision)
+            }
+            Self::PrecisionExceedsWord { precision } => write!(
+                f,
+                "a precision of {} bits does not fit in the ADC's word type",
+                precision
+            ),
+            Self::ValueOutOfRange { index } => write!(
+                f,
+                "the value for point {} does not fit in the value type",
+                index
+            ),
+            Self::InvalidStuckAfter { stuck_after } => write!(
+                f,
+                "a stuck_after of {} would report every reading as stuck",
+                stuck_after
+            ),
+        }
+    }
+}
+
+/// An error returned by
+/// [`AdcInterpolator::read_checked_delta`](crate::AdcInterpolator::read_checked_delta).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeltaError<E> {
+    /// The ADC returned an error.
+    Adc(nb::Error<E>),
+    /// The value changed by more than the allowed amount since the
+    /// previous reading.
+    Exceeded { previous: u32, current: u32 },
+}
+
+impl<E> From<nb::Error<E>> for DeltaError<E> {
+    fn from(error: nb::Error<E>) -> Self {
+        Self::Adc(error)
+    }
+}
+
+impl<E: fmt::Debug> fmt::Display for DeltaError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Adc(error) => write!(f, "ADC error: {:?}", error),
+            Self::Exceeded { previous, current } => write!(
+                f,
+                "value changed from {} to {}, more than the allowed amount",
+                previous, current
+            ),
+        }
+    }
+}
+
+/// An error returned by
+/// [`AdcInterpolator::read_required`](crate::AdcInterpolator::read_required).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReadError<E> {
+    /// The ADC returned an error.
+    Adc(nb::Error<E>),
+    /// The reading was outside the range of the table.
+    OutOfRange,
+    /// The ADC returned exactly `0` or its full-scale code, and the
+    /// configuration's `rail_is_error` is set.
+    Railed,
+    /// The ADC returned the same code as many times in a row as the
+    /// configuration's `stuck_after`.
+    Stuck,
+}
+
+impl<E> From<nb::Error<E>> for ReadError<E> {
+    fn from(error: nb::Error<E>) -> Self {
+        Self::Adc(error)
+    }
+}
+
+impl<E: fmt::Debug> fmt::Display for ReadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Adc(error) => write!(f, "ADC error: {:?}", error),
+            Self::OutOfRange => write!(f, "the reading is outside the range of the table"),
+            Self::Railed => write!(f, "the reading is at the ADC's rail"),
+            Self::Stuck => write!(f, "the ADC returned the same code too many times"),
+        }
+    }
+}
+
+/// An error returned by
+/// [`AdcInterpolator::transfer`](crate::AdcInterpolator::transfer).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferError<A, D> {
+    /// The ADC returned an error.
+    Adc(nb::Error<A>),
+    /// The DAC returned an error.
+    Dac(nb::Error<D>),
+}
+
+impl<A: fmt::Debug, D: fmt::Debug> fmt::Display for TransferError<A, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Adc(error) => write!(f, "ADC error: {:?}", error),
+            Self::Dac(error) => write!(f, "DAC error: {:?}", error),
+        }
+    }
+}
+
+/// An error returned by
+/// [`checked_interpolate`](crate::checked_interpolate).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InterpolateError {
+    /// The end of the segment isn't after its start.
+    InvertedSegment,
+    /// The point to interpolate at is outside the segment.
+    OutOfSegment,
+}
+
+impl fmt::Display for InterpolateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvertedSegment => write!(f, "the segment is not in ascending order"),
+            Self::OutOfSegment => write!(f, "the point is outside the segment"),
+        }
+    }
+}
+
+/// An error returned by a [`WordAdapter`](crate::WordAdapter).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WordError<E> {
+    /// The ADC returned an error.
+    Adc(E),
+    /// The ADC returned a word which doesn't fit in an `i64`.
+    Conversion,
+}
+
+impl<E: fmt::Debug> fmt::Display for WordError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Adc(error) => write!(f, "ADC error: {:?}", error),
+            Self::Conversion => write!(f, "the ADC's word does not fit in an i64"),
+        }
+    }
+}
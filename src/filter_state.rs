@@ -0,0 +1,41 @@
+use crate::noise::Noise;
+
+/// The state an [`AdcInterpolator`](crate::AdcInterpolator) keeps
+/// between readings, such as its accumulator, the last value read
+/// with hysteresis, and its noise estimate.
+///
+/// It can be saved with
+/// [`state`](crate::AdcInterpolator::state) and restored with
+/// [`restore_state`](crate::AdcInterpolator::restore_state), eg. to
+/// keep it in RTC RAM across sleep, so that filtering doesn't start
+/// over.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FilterState<Word> {
+    pub(crate) accumulator: u64,
+    pub(crate) hysteresis: Option<(Word, Option<u32>)>,
+    pub(crate) previous: Option<u32>,
+    pub(crate) rate_previous: Option<u32>,
+    pub(crate) latest: Option<u32>,
+    pub(crate) before_latest: Option<u32>,
+    pub(crate) latest_code: Option<Word>,
+    pub(crate) before_latest_code: Option<Word>,
+    pub(crate) repeats: u32,
+    pub(crate) noise: Noise,
+}
+
+impl<Word> Default for FilterState<Word> {
+    fn default() -> Self {
+        Self {
+            accumulator: 0,
+            hysteresis: None,
+            previous: None,
+            rate_previous: None,
+            latest: None,
+            before_latest: None,
+            latest_code: None,
+            before_latest_code: None,
+            repeats: 0,
+            noise: Noise::default(),
+        }
+    }
+}
@@ -1,28 +1,446 @@
-pub fn interpolate(x0: u32, x1: u32, y0: u32, y1: u32, x: u32) -> u32 {
+use crate::error::InterpolateError;
+
+/// How an [`AdcInterpolator`](crate::AdcInterpolator) computes values
+/// for readings which fall between two points in its table.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Interpolation {
+    /// Linearly interpolates between the two points.
+    #[default]
+    Linear,
+    /// Uses the value of whichever of the two points is closest to
+    /// the reading, preferring the upper point when the reading is
+    /// exactly halfway between them.
+    Nearest,
+    /// Uses the value of the lower of the two points.
+    Floor,
+    /// Interpolates along a smoothstep curve between the two points,
+    /// which eases in and out of each point, so the value changes
+    /// slowly close to the points and fastest halfway between them.
+    Smoothstep,
+    /// Interpolates along a Catmull-Rom spline through the points,
+    /// which gives a smooth curve but may overshoot the values of the
+    /// points. The tangents at the first and last points are those of
+    /// the first and last segments.
+    #[cfg(feature = "float")]
+    CatmullRom,
+}
+
+/// Which segment of an [`AdcInterpolator`](crate::AdcInterpolator)'s
+/// table is used for a reading which falls exactly on a point shared
+/// by two segments.
+///
+/// Both segments give the point's own value with
+/// [`Interpolation::Linear`], but not with [`Interpolation::Floor`],
+/// which uses the value of the lower point of the segment.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Boundary {
+    /// Uses the segment below the point.
+    #[default]
+    Lower,
+    /// Uses the segment above the point.
+    Upper,
+}
+
+/// How an [`AdcInterpolator`](crate::AdcInterpolator) rounds values
+/// which fall between two integers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Rounds toward the value of the lower point of the segment, so
+    /// that values are rounded down in ascending segments and up in
+    /// descending ones.
+    #[default]
+    Truncate,
+    /// Rounds down.
+    Floor,
+    /// Rounds to the nearest integer, rounding up when the value is
+    /// exactly halfway between two integers.
+    Nearest,
+    /// Rounds up.
+    Ceil,
+}
+
+impl RoundingMode {
+    /// Returns whether a quotient with `remainder` after division by
+    /// `divisor` should be rounded away from zero, where the quotient
+    /// is added to a value if `ascending` is set, or subtracted from it
+    /// otherwise.
+    fn round_away(self, remainder: u128, divisor: u128, ascending: bool) -> bool {
+        match self {
+            Self::Truncate => false,
+            Self::Floor => !ascending && remainder != 0,
+            Self::Ceil => ascending && remainder != 0,
+            Self::Nearest if ascending => remainder * 2 >= divisor,
+            Self::Nearest => remainder * 2 > divisor,
+        }
+    }
+}
+
+/// Linearly interpolates between `(x0, y0)` and `(x1, y1)` at `x`.
+///
+/// `x` is clamped to the segment, so the result never falls outside
+/// the range from `y0` to `y1`, even if `x` overshoots `x1`.
+///
+/// This is called for every reading, so rather than branching on
+/// whether the segment is descending, the delta is negated in two's
+/// complement when it is, and added to `y0` with wrapping arithmetic,
+/// which gives the same result as subtracting it.
+#[inline]
+pub fn interpolate(x0: u32, x1: u32, y0: u32, y1: u32, x: u32, rounding: RoundingMode) -> u32 {
+    debug_assert!(x0 < x1, "The segment must be in ascending order");
+
+    let x = x.max(x0).min(x1);
+    let (dx, run) = (u64::from(x - x0), u64::from(x1 - x0));
+    let rise = u64::from(y0.abs_diff(y1));
+    let (delta, remainder) = (dx * rise / run, dx * rise % run);
+    let delta =
+        delta as u32 + u32::from(rounding.round_away(remainder.into(), run.into(), y1 >= y0));
+
+    let descending = u32::from(y0 > y1);
+    let sign = 0u32.wrapping_sub(descending);
+
+    y0.wrapping_add((delta ^ sign).wrapping_add(descending))
+}
+
+/// Interpolates between `(x0, y0)` and `(x1, y1)` along the
+/// smoothstep curve `3t^2 - 2t^3`, where `t` is the position of `x`
+/// between `x0` and `x1` from `0` to `1`. As with [`interpolate`],
+/// `x` is clamped to the segment.
+pub fn smoothstep(x0: u32, x1: u32, y0: u32, y1: u32, x: u32, rounding: RoundingMode) -> u32 {
+    debug_assert!(x0 < x1, "The segment must be in ascending order");
+
+    let x = x.max(x0).min(x1);
+
+    let (n, d) = (u128::from(x - x0), u128::from(x1 - x0));
+    let (numerator, denominator) = (n * n * (3 * d - 2 * n), d * d * d);
+    let scaled = u128::from(y0.abs_diff(y1)) * numerator;
+    let delta = (scaled / denominator) as u32
+        + u32::from(rounding.round_away(scaled % denominator, denominator, y1 >= y0));
+
     if y0 > y1 {
-        y0 - (x - x0) * (y0 - y1) / (x1 - x0)
+        y0 - delta
     } else {
-        y0 + (x - x0) * (y1 - y0) / (x1 - x0)
+        y0 + delta
     }
 }
 
+/// Linearly interpolates between `(x0, y0)` and `(x1, y1)` at `x`,
+/// returning an error instead of panicking or returning a meaningless
+/// value if `x1 <= x0`, or if `x` is outside the segment.
+///
+/// This is useful for validating tables loaded from untrusted
+/// storage.
+///
+/// # Examples
+///
+/// ```
+/// use adc_interpolator::{checked_interpolate, InterpolateError};
+///
+/// assert_eq!(checked_interpolate(0, 10, 0, 100, 5), Ok(50));
+/// assert_eq!(
+///     checked_interpolate(10, 0, 0, 100, 5),
+///     Err(InterpolateError::InvertedSegment)
+/// );
+/// ```
+pub fn checked_interpolate(
+    x0: u32,
+    x1: u32,
+    y0: u32,
+    y1: u32,
+    x: u32,
+) -> Result<u32, InterpolateError> {
+    if x1 <= x0 {
+        return Err(InterpolateError::InvertedSegment);
+    }
+
+    if x < x0 || x > x1 {
+        return Err(InterpolateError::OutOfSegment);
+    }
+
+    Ok(interpolate(x0, x1, y0, y1, x, RoundingMode::Truncate))
+}
+
+/// Linearly interpolates between `(x0, y0)` and `(x1, y1)`, returning
+/// the result as an exact `(numerator, denominator)` fraction.
+///
+/// The numerator is the sum of two products of `u32`s, so it is
+/// returned as a `u64`, which can't overflow.
+pub fn interpolate_ratio(x0: u32, x1: u32, y0: u32, y1: u32, x: u32) -> (u64, u32) {
+    if x1 == x0 {
+        return (y0.into(), 1);
+    }
+
+    (
+        u64::from(y0) * u64::from(x1 - x) + u64::from(y1) * u64::from(x - x0),
+        x1 - x0,
+    )
+}
+
+/// Interpolates between `(x0, y0)` and `(x1, y1)` along the cubic
+/// Hermite curve with tangents `m0` and `m1` at the two points.
+#[cfg(feature = "float")]
+pub fn hermite(x0: u32, x1: u32, y0: u32, y1: u32, m0: f32, m1: f32, x: u32) -> u32 {
+    let h = (x1 - x0) as f32;
+    let t = (x - x0) as f32 / h;
+    let (t2, t3) = (t * t, t * t * t);
+
+    let y = (2.0 * t3 - 3.0 * t2 + 1.0) * y0 as f32
+        + (t3 - 2.0 * t2 + t) * h * m0
+        + (-2.0 * t3 + 3.0 * t2) * y1 as f32
+        + (t3 - t2) * h * m1;
+
+    (y + 0.5) as u32
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn interpolate() {
-        assert_eq!(super::interpolate(0, 10, 0, 100, 0), 0);
-        assert_eq!(super::interpolate(0, 10, 0, 100, 2), 20);
-        assert_eq!(super::interpolate(0, 10, 0, 100, 5), 50);
-        assert_eq!(super::interpolate(0, 10, 0, 100, 8), 80);
-        assert_eq!(super::interpolate(0, 10, 0, 100, 10), 100);
+        assert_eq!(
+            super::interpolate(0, 10, 0, 100, 0, RoundingMode::Truncate),
+            0
+        );
+        assert_eq!(
+            super::interpolate(0, 10, 0, 100, 2, RoundingMode::Truncate),
+            20
+        );
+        assert_eq!(
+            super::interpolate(0, 10, 0, 100, 5, RoundingMode::Truncate),
+            50
+        );
+        assert_eq!(
+            super::interpolate(0, 10, 0, 100, 8, RoundingMode::Truncate),
+            80
+        );
+        assert_eq!(
+            super::interpolate(0, 10, 0, 100, 10, RoundingMode::Truncate),
+            100
+        );
     }
 
     #[test]
     fn interpolate_flipped_y() {
-        assert_eq!(super::interpolate(0, 10, 100, 0, 0), 100);
-        assert_eq!(super::interpolate(0, 10, 100, 0, 2), 80);
-        assert_eq!(super::interpolate(0, 10, 100, 0, 5), 50);
-        assert_eq!(super::interpolate(0, 10, 100, 0, 8), 20);
-        assert_eq!(super::interpolate(0, 10, 100, 0, 10), 0);
+        assert_eq!(
+            super::interpolate(0, 10, 100, 0, 0, RoundingMode::Truncate),
+            100
+        );
+        assert_eq!(
+            super::interpolate(0, 10, 100, 0, 2, RoundingMode::Truncate),
+            80
+        );
+        assert_eq!(
+            super::interpolate(0, 10, 100, 0, 5, RoundingMode::Truncate),
+            50
+        );
+        assert_eq!(
+            super::interpolate(0, 10, 100, 0, 8, RoundingMode::Truncate),
+            20
+        );
+        assert_eq!(
+            super::interpolate(0, 10, 100, 0, 10, RoundingMode::Truncate),
+            0
+        );
+    }
+
+    #[test]
+    fn interpolate_large() {
+        assert_eq!(
+            super::interpolate(0, 1_000_000, 0, 1_000_000, 500_000, RoundingMode::Truncate),
+            500_000
+        );
+    }
+
+    #[test]
+    fn interpolate_rounding() {
+        let interpolate = |y0, y1, rounding| super::interpolate(0, 3, y0, y1, 1, rounding);
+
+        assert_eq!(interpolate(0, 10, RoundingMode::Truncate), 3);
+        assert_eq!(interpolate(0, 10, RoundingMode::Floor), 3);
+        assert_eq!(interpolate(0, 10, RoundingMode::Nearest), 3);
+        assert_eq!(interpolate(0, 10, RoundingMode::Ceil), 4);
+        assert_eq!(interpolate(0, 20, RoundingMode::Nearest), 7);
+
+        assert_eq!(interpolate(10, 0, RoundingMode::Truncate), 7);
+        assert_eq!(interpolate(10, 0, RoundingMode::Floor), 6);
+        assert_eq!(interpolate(10, 0, RoundingMode::Nearest), 7);
+        assert_eq!(interpolate(10, 0, RoundingMode::Ceil), 7);
+        assert_eq!(interpolate(20, 0, RoundingMode::Nearest), 13);
+
+        // Exactly halfway, ties are rounded up either way
+        let halfway = |y0, y1| super::interpolate(0, 2, y0, y1, 1, RoundingMode::Nearest);
+        assert_eq!(halfway(0, 1), 1);
+        assert_eq!(halfway(1, 0), 1);
+    }
+
+    #[test]
+    fn interpolate_stays_within_segment() {
+        let roundings = [
+            RoundingMode::Truncate,
+            RoundingMode::Floor,
+            RoundingMode::Nearest,
+            RoundingMode::Ceil,
+        ];
+
+        for rounding in roundings {
+            assert_eq!(super::interpolate(10, 13, 0, 10, 13, rounding), 10);
+            assert_eq!(super::interpolate(10, 13, 0, 10, 14, rounding), 10);
+            assert_eq!(super::interpolate(10, 13, 0, 10, 9, rounding), 0);
+            assert_eq!(super::interpolate(10, 13, 10, 0, 13, rounding), 0);
+            assert_eq!(super::interpolate(10, 13, 10, 0, u32::MAX, rounding), 0);
+            assert_eq!(super::interpolate(10, 13, 10, 0, 0, rounding), 10);
+
+            assert_eq!(super::smoothstep(10, 13, 0, 10, 13, rounding), 10);
+            assert_eq!(super::smoothstep(10, 13, 0, 10, 100, rounding), 10);
+            assert_eq!(super::smoothstep(10, 13, 10, 0, 100, rounding), 0);
+            assert_eq!(super::smoothstep(10, 13, 10, 0, 0, rounding), 10);
+        }
+    }
+
+    #[test]
+    fn interpolate_matches_exact() {
+        // Rounds `y0 + x * (y1 - y0) / 7` computed exactly
+        let exact = |y0: u32, y1: u32, x: u32, rounding| {
+            let numerator = i128::from(x) * (i128::from(y1) - i128::from(y0));
+            let delta = match rounding {
+                RoundingMode::Truncate => numerator / 7,
+                RoundingMode::Floor => numerator.div_euclid(7),
+                RoundingMode::Nearest => (2 * numerator + 7).div_euclid(14),
+                RoundingMode::Ceil => -(-numerator).div_euclid(7),
+            };
+
+            (i128::from(y0) + delta) as u32
+        };
+
+        let ys = [0, 1, 10, 99, u32::MAX - 1, u32::MAX];
+        let roundings = [
+            RoundingMode::Truncate,
+            RoundingMode::Floor,
+            RoundingMode::Nearest,
+            RoundingMode::Ceil,
+        ];
+
+        for y0 in ys {
+            for y1 in ys {
+                for x in 0..=7 {
+                    for rounding in roundings {
+                        assert_eq!(
+                            super::interpolate(0, 7, y0, y1, x, rounding),
+                            exact(y0, y1, x, rounding),
+                            "({y0}, {y1}, {x}, {rounding:?})"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn smoothstep_rounding() {
+        assert_eq!(super::smoothstep(0, 10, 0, 1000, 1, RoundingMode::Ceil), 28);
+        assert_eq!(super::smoothstep(0, 3, 0, 10, 1, RoundingMode::Floor), 2);
+        assert_eq!(super::smoothstep(0, 3, 0, 10, 1, RoundingMode::Nearest), 3);
+        assert_eq!(super::smoothstep(0, 3, 10, 0, 1, RoundingMode::Truncate), 8);
+        assert_eq!(super::smoothstep(0, 3, 10, 0, 1, RoundingMode::Floor), 7);
+        assert_eq!(super::smoothstep(0, 3, 10, 0, 1, RoundingMode::Nearest), 7);
+    }
+
+    #[test]
+    fn checked_interpolate() {
+        assert_eq!(super::checked_interpolate(0, 10, 0, 100, 0), Ok(0));
+        assert_eq!(super::checked_interpolate(0, 10, 100, 0, 2), Ok(80));
+        assert_eq!(super::checked_interpolate(0, 10, 0, 100, 10), Ok(100));
+        assert_eq!(
+            super::checked_interpolate(10, 10, 0, 100, 10),
+            Err(InterpolateError::InvertedSegment)
+        );
+        assert_eq!(
+            super::checked_interpolate(10, 0, 0, 100, 5),
+            Err(InterpolateError::InvertedSegment)
+        );
+        assert_eq!(
+            super::checked_interpolate(5, 10, 0, 100, 4),
+            Err(InterpolateError::OutOfSegment)
+        );
+        assert_eq!(
+            super::checked_interpolate(5, 10, 0, 100, 11),
+            Err(InterpolateError::OutOfSegment)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn interpolate_inverted() {
+        super::interpolate(10, 0, 0, 100, 5, RoundingMode::Truncate);
+    }
+
+    #[test]
+    fn smoothstep() {
+        assert_eq!(
+            super::smoothstep(0, 10, 0, 1000, 0, RoundingMode::Truncate),
+            0
+        );
+        assert_eq!(
+            super::smoothstep(0, 10, 0, 1000, 1, RoundingMode::Truncate),
+            28
+        );
+        assert_eq!(
+            super::smoothstep(0, 10, 0, 1000, 2, RoundingMode::Truncate),
+            104
+        );
+        assert_eq!(
+            super::smoothstep(0, 10, 0, 1000, 5, RoundingMode::Truncate),
+            500
+        );
+        assert_eq!(
+            super::smoothstep(0, 10, 0, 1000, 8, RoundingMode::Truncate),
+            896
+        );
+        assert_eq!(
+            super::smoothstep(0, 10, 0, 1000, 10, RoundingMode::Truncate),
+            1000
+        );
+        assert_eq!(
+            super::smoothstep(0, 10, 1000, 0, 2, RoundingMode::Truncate),
+            896
+        );
+        assert_eq!(
+            super::smoothstep(0, 10, 1000, 0, 10, RoundingMode::Truncate),
+            0
+        );
+    }
+
+    #[test]
+    fn interpolate_ratio() {
+        assert_eq!(super::interpolate_ratio(0, 10, 0, 100, 0), (0, 10));
+        assert_eq!(super::interpolate_ratio(0, 10, 0, 100, 3), (300, 10));
+        assert_eq!(super::interpolate_ratio(0, 3, 100, 0, 1), (200, 3));
+        assert_eq!(super::interpolate_ratio(0, 3, 100, 0, 3), (0, 3));
+        assert_eq!(super::interpolate_ratio(5, 5, 7, 9, 5), (7, 1));
+    }
+
+    #[test]
+    fn interpolate_ratio_large() {
+        assert_eq!(
+            super::interpolate_ratio(0, 65535, 100_000, 200_000, 30_000),
+            (9_553_500_000, 65535)
+        );
+        assert_eq!(
+            super::interpolate_ratio(0, u32::MAX, u32::MAX, u32::MAX, 1),
+            (u64::from(u32::MAX) * u64::from(u32::MAX), u32::MAX)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn hermite() {
+        assert_eq!(super::hermite(0, 10, 0, 100, 10.0, 10.0, 0), 0);
+        assert_eq!(super::hermite(0, 10, 0, 100, 10.0, 10.0, 5), 50);
+        assert_eq!(super::hermite(0, 10, 0, 100, 10.0, 10.0, 10), 100);
+        assert_eq!(super::hermite(0, 10, 0, 100, 0.0, 0.0, 2), 10);
+        assert_eq!(super::hermite(0, 10, 0, 100, 0.0, 0.0, 5), 50);
+        assert_eq!(super::hermite(0, 10, 100, 0, -20.0, 0.0, 2), 64);
     }
 }
@@ -27,6 +27,7 @@
 //!         (200, 30), // 200 mV -> 30
 //!         (300, 10), // 300 mV -> 10
 //!     ],
+//!     ..Default::default()
 //! };
 //!
 //! let mut interpolator = AdcInterpolator::new(pin, config);
@@ -36,6 +37,47 @@
 //! ```
 
 mod adc_interpolator;
+mod adc_interpolator_ref;
+mod bilinear_interpolator;
+mod config;
+mod dac;
+mod error;
+mod filter_state;
 mod interpolate;
+mod macros;
+mod mux_interpolator;
+mod noise;
+mod one_shot_owned;
+mod polling_sensor;
+mod reading;
+mod table;
+#[cfg(feature = "units")]
+mod units;
+mod window_sum;
+mod word_adapter;
+#[cfg(feature = "async")]
+mod yield_now;
 
-pub use self::adc_interpolator::{AdcInterpolator, Config};
+pub use self::{
+    adc_interpolator::AdcInterpolator,
+    adc_interpolator_ref::AdcInterpolatorRef,
+    bilinear_interpolator::{BilinearConfig, BilinearInterpolator},
+    config::{min_precision_for, Config, OutOfRange, ValueConfig},
+    dac::Dac,
+    error::{ConfigError, DeltaError, InterpolateError, ReadError, TransferError, WordError},
+    filter_state::FilterState,
+    interpolate::{checked_interpolate, Boundary, Interpolation, RoundingMode},
+    mux_interpolator::MuxInterpolator,
+    one_shot_owned::OneShotOwned,
+    polling_sensor::{PollingSensor, SensorOutput},
+    reading::{Diagnostic, FullReading, Reading, Trend},
+    table::{Table, TableRef},
+    window_sum::WindowSum,
+    word_adapter::WordAdapter,
+};
+
+#[cfg(feature = "units")]
+pub use self::units::Millivolts;
+
+#[doc(hidden)]
+pub use self::macros::__validate_points;
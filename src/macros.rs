@@ -0,0 +1,115 @@
+/// Builds a [`Config`](crate::Config) from literal `(voltage, value)`
+/// points, checking at compile time that there are at least two
+/// points and that their voltages are in strictly ascending order.
+///
+/// The other fields have the same defaults as a `Config` converted
+/// from an array of points, and can be replaced with the builder
+/// methods.
+///
+/// # Examples
+///
+/// ```
+/// use adc_interpolator::adc_config;
+///
+/// let config = adc_config![(100, 40), (200, 30), (300, 10)]
+///     .with_max_voltage(1000)
+///     .with_precision(12);
+///
+/// assert_eq!(config.voltage_to_values, [(100, 40), (200, 30), (300, 10)]);
+/// ```
+///
+/// Points out of order fail to compile:
+///
+/// ```compile_fail
+/// use adc_interpolator::adc_config;
+///
+/// let config = adc_config![(200, 30), (100, 40)];
+/// ```
+///
+/// As do duplicate voltages:
+///
+/// ```compile_fail
+/// use adc_interpolator::adc_config;
+///
+/// let config = adc_config![(100, 40), (100, 30)];
+/// ```
+///
+/// And a single point:
+///
+/// ```compile_fail
+/// use adc_interpolator::adc_config;
+///
+/// let config = adc_config![(100, 40)];
+/// ```
+#[macro_export]
+macro_rules! adc_config {
+    ($(($voltage:expr, $value:expr)),+ $(,)?) => {
+        $crate::Config::from(const {
+            let points = [$(($voltage, $value)),+];
+            $crate::__validate_points(&points);
+            points
+        })
+    };
+}
+
+/// Panics if `points` has fewer than two points, or if their voltages
+/// aren't in strictly ascending order. Used by [`adc_config!`] to
+/// validate its points at compile time.
+#[doc(hidden)]
+pub const fn __validate_points(points: &[(u32, u32)]) {
+    assert!(points.len() >= 2, "The table must have at least two points");
+
+    let mut index = 1;
+
+    while index < points.len() {
+        let (previous, current) = (points[index - 1].0, points[index].0);
+
+        assert!(
+            previous != current,
+            "The table must not have duplicate voltages"
+        );
+        assert!(
+            previous < current,
+            "The values in table must be in ascending order by voltage"
+        );
+
+        index += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adc_config() {
+        let config = adc_config![(100, 40), (200, 30), (300, 10),];
+
+        assert_eq!(config.max_voltage, 3300);
+        assert_eq!(config.precision, 12);
+        assert_eq!(config.voltage_to_values, [(100, 40), (200, 30), (300, 10)]);
+    }
+
+    #[test]
+    fn validate_points() {
+        __validate_points(&[(100, 40), (200, 30)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn validate_points_too_short() {
+        __validate_points(&[(100, 40)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn validate_points_duplicate() {
+        __validate_points(&[(100, 40), (200, 30), (200, 10)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn validate_points_not_ascending() {
+        __validate_points(&[(200, 40), (100, 30)]);
+    }
+}
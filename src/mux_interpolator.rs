@@ -0,0 +1,238 @@
+use crate::{
+    config::ValueConfig,
+    table::{is_sorted, Table},
+};
+use embedded_hal::adc::{Channel, OneShot};
+
+/// An interpolator for several sensors multiplexed onto a single pin,
+/// each with its own table.
+///
+/// One of the tables is selected at a time, and is used for every
+/// reading until another is selected.
+#[derive(Debug)]
+pub struct MuxInterpolator<Pin, Word, const LENGTH: usize, const N: usize, V = u32> {
+    pin: Pin,
+    tables: [Table<Word, LENGTH, V>; N],
+    selected: usize,
+}
+
+type Error<Adc, ADC, Word, Pin> = nb::Error<<Adc as OneShot<ADC, Word, Pin>>::Error>;
+
+impl<Pin, Word, const LENGTH: usize, const N: usize, V> MuxInterpolator<Pin, Word, LENGTH, N, V> {
+    /// Returns an interpolator reading from `pin`, with a table for
+    /// each of the provided `configs`. The first table is selected.
+    ///
+    /// The values in each config's `voltage_to_values` field must be
+    /// in ascending order by voltage or this function will panic when
+    /// running in debug mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{Config, MuxInterpolator};
+    /// # use embedded_hal_mock::adc::MockChan0;
+    /// #
+    /// # let pin = MockChan0 {};
+    ///
+    /// let temperature = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [(100, 40), (200, 30), (300, 10)],
+    ///     ..Default::default()
+    /// };
+    /// let pressure = Config {
+    ///     voltage_to_values: [(100, 500), (500, 1000), (900, 1500)],
+    ///     ..temperature
+    /// };
+    ///
+    /// let interpolator = MuxInterpolator::new(pin, [temperature, pressure]);
+    /// # let interpolator: MuxInterpolator<MockChan0, u16, 3, 2> = interpolator;
+    /// ```
+    pub fn new<ADC>(pin: Pin, configs: [ValueConfig<LENGTH, V>; N]) -> Self
+    where
+        Word: Copy + TryFrom<u32>,
+        Pin: Channel<ADC>,
+        V: Copy,
+    {
+        const { assert!(N >= 1, "There must be at least one table") };
+
+        debug_assert!(
+            configs.iter().all(is_sorted),
+            "The values in table must be in ascending order by voltage"
+        );
+
+        let tables = configs.map(|config| Table::from_codes(config, config.codes().unwrap()));
+
+        Self {
+            pin,
+            tables,
+            selected: 0,
+        }
+    }
+
+    /// Destroys the interpolator and returns the `Pin`.
+    pub fn free(self) -> Pin {
+        self.pin
+    }
+
+    /// Selects the table at `index` for future readings.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no table at `index`.
+    pub fn select(&mut self, index: usize) {
+        assert!(index < N, "There is no table at index {}", index);
+
+        self.selected = index;
+    }
+
+    /// Returns the index of the selected table.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Returns a value based on the selected table, as
+    /// [`AdcInterpolator::read`](crate::AdcInterpolator::read) does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{Config, MuxInterpolator};
+    /// # use embedded_hal_mock::{
+    /// #     adc::{Mock, MockChan0, Transaction},
+    /// #     common::Generic,
+    /// #     MockError,
+    /// # };
+    /// #
+    /// # let expectations: [Transaction<u16>; 2] = [
+    /// #     Transaction::read(0, 614),
+    /// #     Transaction::read(0, 1229),
+    /// # ];
+    /// # let mut adc = Mock::new(&expectations);
+    /// # let pin = MockChan0 {};
+    ///
+    /// let temperature = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [(100, 40), (200, 30), (300, 10)],
+    ///     ..Default::default()
+    /// };
+    /// let pressure = Config {
+    ///     voltage_to_values: [(100, 500), (500, 1000), (900, 1500)],
+    ///     ..temperature
+    /// };
+    ///
+    /// let mut interpolator = MuxInterpolator::new(pin, [temperature, pressure]);
+    ///
+    /// // With voltage at 150 mV, the temperature is 35
+    /// assert_eq!(interpolator.read(&mut adc), Ok(Some(35)));
+    ///
+    /// interpolator.select(1);
+    ///
+    /// // With voltage at ~300 mV, the pressure is 750
+    /// assert_eq!(interpolator.read(&mut adc), Ok(Some(750)));
+    /// ```
+    pub fn read<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+    ) -> Result<Option<u32>, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let adc_value = adc.read(&mut self.pin)?;
+
+        Ok(self.tables[self.selected].process(adc_value.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use embedded_hal_mock::{
+        adc::{Mock, MockChan0, Transaction},
+        MockError,
+    };
+    use std::io::ErrorKind;
+
+    fn configs() -> [Config<2>; 3] {
+        let config = Config {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [(100, 10), (300, 30)],
+            ..Default::default()
+        };
+
+        [
+            config,
+            Config {
+                voltage_to_values: [(100, 100), (300, 300)],
+                ..config
+            },
+            Config {
+                voltage_to_values: [(200, 50), (300, 0)],
+                ..config
+            },
+        ]
+    }
+
+    fn interpolator() -> MuxInterpolator<MockChan0, u16, 2, 3> {
+        MuxInterpolator::new(MockChan0 {}, configs())
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_if_unsorted() {
+        let mut configs = configs();
+        configs[1].voltage_to_values = [(300, 30), (100, 10)];
+
+        MuxInterpolator::<_, u16, 2, 3>::new(MockChan0 {}, configs);
+    }
+
+    #[test]
+    #[should_panic]
+    fn select_out_of_range() {
+        interpolator().select(3);
+    }
+
+    #[test]
+    fn reads_selected_table() {
+        let mut interpolator = interpolator();
+
+        let expectations = [
+            Transaction::read(0, 819),
+            Transaction::read(0, 819),
+            Transaction::read(0, 819),
+            Transaction::read(0, 409),
+        ];
+        let mut adc = Mock::new(&expectations);
+
+        assert_eq!(interpolator.selected(), 0);
+        assert_eq!(interpolator.read(&mut adc), Ok(Some(20)));
+
+        interpolator.select(1);
+        assert_eq!(interpolator.selected(), 1);
+        assert_eq!(interpolator.read(&mut adc), Ok(Some(200)));
+
+        interpolator.select(2);
+        assert_eq!(interpolator.read(&mut adc), Ok(Some(50)));
+        assert_eq!(interpolator.read(&mut adc), Ok(None));
+
+        adc.done();
+    }
+
+    #[test]
+    fn error() {
+        let mut interpolator = interpolator();
+
+        let expectations =
+            [Transaction::read(0, 0).with_error(MockError::Io(ErrorKind::InvalidData))];
+        let mut adc = Mock::new(&expectations);
+
+        assert!(interpolator.read(&mut adc).is_err());
+        adc.done();
+    }
+}
@@ -0,0 +1,93 @@
+/// The number of fractional bits in the fixed-point mean.
+const FRACTION_BITS: u32 = 8;
+
+/// The weight of each new sample is `1 / 2^WEIGHT_BITS`.
+const WEIGHT_BITS: u32 = 3;
+
+/// The number of samples needed before an estimate is returned.
+const MIN_SAMPLES: u32 = 1 << WEIGHT_BITS;
+
+/// An estimate of the noise in a series of ADC codes, using the
+/// exponentially weighted moving variance of the codes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Noise {
+    /// The moving mean, with `FRACTION_BITS` fractional bits.
+    mean: i64,
+    /// The moving variance, with `2 * FRACTION_BITS` fractional bits.
+    variance: u64,
+    samples: u32,
+}
+
+impl Noise {
+    /// Adds the ADC code `code` to the estimate.
+    pub(crate) fn update(&mut self, code: i64) {
+        let code = code << FRACTION_BITS;
+
+        if self.samples == 0 {
+            self.mean = code;
+            self.samples = 1;
+            return;
+        }
+
+        let diff = code - self.mean;
+        let squared = diff.unsigned_abs().saturating_mul(diff.unsigned_abs());
+
+        self.mean += diff >> WEIGHT_BITS;
+        self.variance = self.variance.saturating_add(squared >> WEIGHT_BITS);
+        self.variance -= self.variance >> WEIGHT_BITS;
+        self.samples = self.samples.saturating_add(1);
+    }
+
+    /// Returns the standard deviation of the codes, rounded to the
+    /// nearest code, or `0` if too few codes have been added.
+    pub(crate) fn estimate(&self) -> u32 {
+        if self.samples < MIN_SAMPLES {
+            return 0;
+        }
+
+        let deviation = self.variance.isqrt();
+
+        ((deviation + (1 << (FRACTION_BITS - 1))) >> FRACTION_BITS) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noise(codes: impl IntoIterator<Item = i64>) -> Noise {
+        let mut noise = Noise::default();
+
+        for code in codes {
+            noise.update(code);
+        }
+
+        noise
+    }
+
+    #[test]
+    fn too_few_samples() {
+        assert_eq!(noise([]).estimate(), 0);
+        assert_eq!(noise([100, 200, 100, 200, 100, 200, 100]).estimate(), 0);
+    }
+
+    #[test]
+    fn constant() {
+        assert_eq!(noise([1000; 100]).estimate(), 0);
+    }
+
+    fn alternating(amplitude: i64) -> impl Iterator<Item = i64> {
+        (0..100).map(move |i| if i % 2 == 0 { 1000 } else { 1000 + amplitude })
+    }
+
+    #[test]
+    fn negative() {
+        assert_eq!(noise(alternating(4).map(|code| code - 2000)).estimate(), 2);
+    }
+
+    #[test]
+    fn noisy() {
+        assert_eq!(noise(alternating(4)).estimate(), 2);
+        assert_eq!(noise(alternating(40)).estimate(), 20);
+    }
+}
@@ -0,0 +1,15 @@
+/// A single-shot ADC which takes the channel to read by value and
+/// returns it along with the result, for HALs which don't follow
+/// embedded-hal's [`OneShot`](embedded_hal::adc::OneShot) convention
+/// of borrowing the channel.
+///
+/// Used by
+/// [`AdcInterpolator::read_owned`](crate::AdcInterpolator::read_owned).
+pub trait OneShotOwned<ADC, Word, Pin> {
+    /// Error type returned by ADC methods.
+    type Error;
+
+    /// Requests that the ADC begin a conversion on the specified
+    /// pin, returning the pin along with the result.
+    fn read(&mut self, pin: Pin) -> (Pin, nb::Result<Word, Self::Error>);
+}
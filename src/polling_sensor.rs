@@ -0,0 +1,350 @@
+use crate::adc_interpolator::AdcInterpolator;
+use embedded_hal::adc::{Channel, OneShot};
+
+/// A sensor which reads an [`AdcInterpolator`] through a fixed
+/// pipeline of oversampling, smoothing and output hysteresis, for use
+/// in a polling loop.
+///
+/// Each [`poll`](PollingSensor::poll) averages a number of samples
+/// (see [`with_oversampling`](PollingSensor::with_oversampling)),
+/// smooths the values with an exponential moving average (see
+/// [`with_smoothing`](PollingSensor::with_smoothing)), and holds the
+/// output until it moves by more than a band (see
+/// [`with_hysteresis`](PollingSensor::with_hysteresis)). Readings
+/// outside the range of the table are handled according to the
+/// interpolator's `out_of_range_low` and `out_of_range_high`.
+///
+/// # Examples
+///
+/// ```
+/// use adc_interpolator::{AdcInterpolator, Config, PollingSensor, SensorOutput};
+/// # use embedded_hal_mock::{
+/// #     adc::{Mock, MockChan0, Transaction},
+/// #     common::Generic,
+/// #     MockError,
+/// # };
+/// #
+/// # let expectations: [Transaction<u16>; 4] = [
+/// #     Transaction::read(0, 612),
+/// #     Transaction::read(0, 616),
+/// #     Transaction::read(0, 652),
+/// #     Transaction::read(0, 658),
+/// # ];
+/// # let mut adc = Mock::new(&expectations);
+/// # let pin = MockChan0 {};
+///
+/// let config = Config {
+///     max_voltage: 1000,
+///     precision: 12,
+///     voltage_to_values: [
+///         (100, 40),
+///         (200, 30),
+///         (300, 10),
+///     ],
+///     ..Default::default()
+/// };
+///
+/// let interpolator = AdcInterpolator::new(pin, config);
+/// let mut sensor = PollingSensor::new(interpolator)
+///     .with_oversampling(2)
+///     .with_hysteresis(1);
+///
+/// assert_eq!(sensor.poll(&mut adc), Ok(SensorOutput::Changed(35)));
+/// // The value moved from 35 to 34, which is within the band
+/// assert_eq!(sensor.poll(&mut adc), Ok(SensorOutput::Unchanged(35)));
+/// ```
+#[derive(Debug)]
+pub struct PollingSensor<Pin, Word, const LENGTH: usize, V = u32> {
+    interpolator: AdcInterpolator<Pin, Word, LENGTH, V>,
+    oversampling: u32,
+    smoothing: u32,
+    hysteresis: u32,
+    average: Option<u64>,
+    output: Option<u32>,
+}
+
+/// The output of a [`PollingSensor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SensorOutput {
+    /// The value moved by more than the hysteresis band, or is the
+    /// first value since the sensor was created or reset.
+    Changed(u32),
+    /// The value is within the hysteresis band of the previous output,
+    /// which is repeated.
+    Unchanged(u32),
+    /// The reading was outside the range of the table.
+    OutOfRange,
+}
+
+impl SensorOutput {
+    /// Returns the value of the output, unless the reading was outside
+    /// the range of the table.
+    pub fn value(self) -> Option<u32> {
+        match self {
+            Self::Changed(value) | Self::Unchanged(value) => Some(value),
+            Self::OutOfRange => None,
+        }
+    }
+}
+
+type Error<Adc, ADC, Word, Pin> = nb::Error<<Adc as OneShot<ADC, Word, Pin>>::Error>;
+
+impl<Pin, Word, const LENGTH: usize, V> PollingSensor<Pin, Word, LENGTH, V> {
+    /// Returns a sensor reading from `interpolator`, taking one sample
+    /// per poll, with no smoothing or hysteresis.
+    pub fn new(interpolator: AdcInterpolator<Pin, Word, LENGTH, V>) -> Self {
+        Self {
+            interpolator,
+            oversampling: 1,
+            smoothing: 0,
+            hysteresis: 0,
+            average: None,
+            output: None,
+        }
+    }
+
+    /// Returns the sensor, averaging `samples` samples per poll.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is `0`.
+    pub fn with_oversampling(self, samples: u32) -> Self {
+        assert!(samples != 0, "samples must not be zero");
+
+        Self {
+            oversampling: samples,
+            ..self
+        }
+    }
+
+    /// Returns the sensor, smoothing values with an exponential moving
+    /// average which moves `1 / 2^shift` of the way towards each new
+    /// value. A `shift` of `0` disables smoothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shift` is greater than `32`.
+    pub fn with_smoothing(self, shift: u32) -> Self {
+        assert!(shift <= 32, "shift must not be greater than 32");
+
+        Self {
+            smoothing: shift,
+            ..self
+        }
+    }
+
+    /// Returns the sensor, holding its output until the smoothed value
+    /// moves by more than `band`.
+    pub fn with_hysteresis(self, band: u32) -> Self {
+        Self {
+            hysteresis: band,
+            ..self
+        }
+    }
+
+    /// Returns the interpolator.
+    pub fn interpolator(&self) -> &AdcInterpolator<Pin, Word, LENGTH, V> {
+        &self.interpolator
+    }
+
+    /// Destroys the sensor and returns the interpolator.
+    pub fn free(self) -> AdcInterpolator<Pin, Word, LENGTH, V> {
+        self.interpolator
+    }
+
+    /// Clears the moving average and the held output, so that the next
+    /// poll starts over.
+    pub fn reset(&mut self) {
+        self.average = None;
+        self.output = None;
+    }
+
+    /// Reads the sensor through its pipeline, stopping at the first
+    /// ADC error and returning it.
+    ///
+    /// Readings outside the range of the table leave the moving
+    /// average and the held output as they are.
+    pub fn poll<Adc, ADC>(
+        &mut self,
+        adc: &mut Adc,
+    ) -> Result<SensorOutput, Error<Adc, ADC, Word, Pin>>
+    where
+        Word: Copy + Into<i64> + PartialEq + PartialOrd,
+        Pin: Channel<ADC>,
+        Adc: OneShot<ADC, Word, Pin>,
+        V: Copy + Into<u32>,
+    {
+        let Some(value) = self.interpolator.read_averaged(adc, self.oversampling)? else {
+            return Ok(SensorOutput::OutOfRange);
+        };
+
+        let value = self.smooth(value);
+
+        Ok(match self.output {
+            Some(output) if output.abs_diff(value) <= self.hysteresis => {
+                SensorOutput::Unchanged(output)
+            }
+            _ => {
+                self.output = Some(value);
+                SensorOutput::Changed(value)
+            }
+        })
+    }
+
+    /// Adds `value` to the moving average, and returns the average.
+    fn smooth(&mut self, value: u32) -> u32 {
+        // The average is kept scaled up by `2^smoothing`, so that
+        // fractions of a value aren't lost
+        let average = match self.average {
+            Some(average) => average - (average >> self.smoothing) + u64::from(value),
+            None => u64::from(value) << self.smoothing,
+        };
+        self.average = Some(average);
+
+        (average >> self.smoothing) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Config, OutOfRange};
+    use embedded_hal_mock::{
+        adc::{Mock, MockChan0, Transaction},
+        MockError,
+    };
+    use std::io::ErrorKind;
+
+    fn sensor() -> PollingSensor<MockChan0, u16, 3> {
+        let config = Config {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [(100, 10), (200, 30), (300, 40)],
+            ..Default::default()
+        };
+
+        PollingSensor::new(AdcInterpolator::new(MockChan0 {}, config))
+    }
+
+    #[test]
+    fn poll() {
+        let mut sensor = sensor();
+
+        let expectations = [
+            Transaction::read(0, 409),
+            Transaction::read(0, 819),
+            Transaction::read(0, 4095),
+        ];
+        let mut adc = Mock::new(&expectations);
+
+        assert_eq!(sensor.poll(&mut adc), Ok(SensorOutput::Changed(10)));
+        assert_eq!(sensor.poll(&mut adc), Ok(SensorOutput::Changed(30)));
+        assert_eq!(sensor.poll(&mut adc), Ok(SensorOutput::OutOfRange));
+        assert_eq!(SensorOutput::OutOfRange.value(), None);
+        assert_eq!(SensorOutput::Unchanged(5).value(), Some(5));
+        adc.done();
+    }
+
+    #[test]
+    fn oversampling() {
+        let mut sensor = sensor().with_oversampling(3);
+
+        let expectations = [
+            Transaction::read(0, 409),
+            Transaction::read(0, 614),
+            Transaction::read(0, 819),
+        ];
+        let mut adc = Mock::new(&expectations);
+
+        assert_eq!(sensor.poll(&mut adc), Ok(SensorOutput::Changed(20)));
+        assert_eq!(sensor.interpolator().sample_count(), 3);
+        adc.done();
+    }
+
+    #[test]
+    fn smoothing() {
+        let mut sensor = sensor().with_smoothing(1);
+
+        let expectations = [
+            Transaction::read(0, 409),
+            Transaction::read(0, 819),
+            Transaction::read(0, 819),
+            Transaction::read(0, 819),
+            Transaction::read(0, 0),
+            Transaction::read(0, 819),
+        ];
+        let mut adc = Mock::new(&expectations);
+
+        assert_eq!(sensor.poll(&mut adc), Ok(SensorOutput::Changed(10)));
+        assert_eq!(sensor.poll(&mut adc), Ok(SensorOutput::Changed(20)));
+        assert_eq!(sensor.poll(&mut adc), Ok(SensorOutput::Changed(25)));
+        assert_eq!(sensor.poll(&mut adc), Ok(SensorOutput::Changed(27)));
+        // Out of range readings don't affect the average
+        assert_eq!(sensor.poll(&mut adc), Ok(SensorOutput::OutOfRange));
+        assert_eq!(sensor.poll(&mut adc), Ok(SensorOutput::Changed(29)));
+        adc.done();
+    }
+
+    #[test]
+    fn hysteresis() {
+        let mut sensor = sensor().with_hysteresis(2);
+
+        let expectations = [
+            Transaction::read(0, 614),
+            Transaction::read(0, 655),
+            Transaction::read(0, 573),
+            Transaction::read(0, 676),
+            Transaction::read(0, 676),
+        ];
+        let mut adc = Mock::new(&expectations);
+
+        assert_eq!(sensor.poll(&mut adc), Ok(SensorOutput::Changed(20)));
+        assert_eq!(sensor.poll(&mut adc), Ok(SensorOutput::Unchanged(20)));
+        assert_eq!(sensor.poll(&mut adc), Ok(SensorOutput::Unchanged(20)));
+        assert_eq!(sensor.poll(&mut adc), Ok(SensorOutput::Changed(23)));
+
+        sensor.reset();
+        assert_eq!(sensor.poll(&mut adc), Ok(SensorOutput::Changed(23)));
+        adc.done();
+    }
+
+    #[test]
+    fn out_of_range_policy() {
+        let config = Config {
+            max_voltage: 1000,
+            precision: 12,
+            voltage_to_values: [(100, 10), (200, 30), (300, 40)],
+            out_of_range_high: OutOfRange::Clamp,
+            ..Default::default()
+        };
+        let mut sensor: PollingSensor<_, u16, 3> =
+            PollingSensor::new(AdcInterpolator::new(MockChan0 {}, config));
+
+        let expectations = [Transaction::read(0, 4095), Transaction::read(0, 0)];
+        let mut adc = Mock::new(&expectations);
+
+        assert_eq!(sensor.poll(&mut adc), Ok(SensorOutput::Changed(40)));
+        assert_eq!(sensor.poll(&mut adc), Ok(SensorOutput::OutOfRange));
+        adc.done();
+    }
+
+    #[test]
+    fn error() {
+        let mut sensor = sensor().with_oversampling(2);
+
+        let expectations = [
+            Transaction::read(0, 409),
+            Transaction::read(0, 0).with_error(MockError::Io(ErrorKind::InvalidData)),
+        ];
+        let mut adc = Mock::new(&expectations);
+
+        assert!(sensor.poll(&mut adc).is_err());
+        adc.done();
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_if_oversampling_is_zero() {
+        let _ = sensor().with_oversampling(0);
+    }
+}
@@ -0,0 +1,70 @@
+/// The result of a detailed read from an
+/// [`AdcInterpolator`](crate::AdcInterpolator).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reading {
+    /// The reading was within the range of the table, with the given
+    /// value.
+    InRange(u32),
+    /// The reading was below the first point in the table.
+    Below,
+    /// The reading was above the last point in the table.
+    Above,
+    /// The ADC returned its lowest or highest possible code, which
+    /// usually indicates that the input is disconnected or shorted.
+    Saturated,
+    /// The ADC returned a code outside the configured plausible range,
+    /// which the sensor can't physically produce.
+    Implausible,
+}
+
+/// The direction a value moved in since the previous value, as
+/// returned by
+/// [`AdcInterpolator::read_trend`](crate::AdcInterpolator::read_trend).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Trend {
+    /// The value is higher than the previous value.
+    Rising,
+    /// The value is lower than the previous value.
+    Falling,
+    /// The value is the same as the previous value, or there is no
+    /// value to compare.
+    Steady,
+}
+
+/// Everything known about a single ADC conversion, as returned by
+/// [`AdcInterpolator::read_full`](crate::AdcInterpolator::read_full).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FullReading<Word> {
+    /// The raw code returned by the ADC.
+    pub code: Word,
+    /// The voltage (mV) of the code, as returned by
+    /// [`read_voltage`](crate::AdcInterpolator::read_voltage).
+    pub voltage_mv: u32,
+    /// The value of the code, as returned by
+    /// [`read`](crate::AdcInterpolator::read).
+    pub value: Option<u32>,
+}
+
+/// Why a reading had no value, as returned by
+/// [`AdcInterpolator::read_or_report`](crate::AdcInterpolator::read_or_report).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Diagnostic<Word> {
+    /// The raw code returned by the ADC.
+    pub code: Word,
+    /// The ADC codes of the first and last points in the table.
+    pub table_range: (Word, Word),
+    /// Where the reading fell, eg. [`Reading::Below`] if it was below
+    /// the first point in the table.
+    pub reading: Reading,
+}
+
+impl Reading {
+    /// Returns the value of the reading if it was within the range of
+    /// the table.
+    pub fn value(self) -> Option<u32> {
+        match self {
+            Self::InRange(value) => Some(value),
+            _ => None,
+        }
+    }
+}
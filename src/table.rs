@@ -0,0 +1,527 @@
+#[cfg(feature = "float")]
+use crate::interpolate::hermite;
+use crate::{
+    config::{OutOfRange, ValueConfig},
+    interpolate::{interpolate, smoothstep, Boundary, Interpolation},
+    reading::Reading,
+};
+
+/// The points of a configuration, along with the ADC codes derived
+/// from their voltages.
+///
+/// The values of the points are stored separately from the rest of
+/// the configuration, so that values narrower than a `u32` make the
+/// table smaller.
+///
+/// Every interpolator has its own table, but a table can also be
+/// built once and shared by several [`AdcInterpolatorRef`]s reading
+/// sensors with the same calibration on different pins, or copied into
+/// several interpolators with [`AdcInterpolator::from_table`].
+///
+/// [`AdcInterpolatorRef`]: crate::AdcInterpolatorRef
+/// [`AdcInterpolator::from_table`]: crate::AdcInterpolator::from_table
+#[derive(Clone, Copy, Debug)]
+pub struct Table<Word, const LENGTH: usize, V = u32> {
+    pub(crate) config: ValueConfig<LENGTH, ()>,
+    pub(crate) values: [V; LENGTH],
+    pub(crate) codes: [Word; LENGTH],
+    pub(crate) sorted: bool,
+}
+
+impl<Word, const LENGTH: usize, V> From<ValueConfig<LENGTH, V>> for Table<Word, LENGTH, V>
+where
+    Word: Copy + TryFrom<u32>,
+    V: Copy,
+{
+    /// Returns a table using `config`, as [`Table::new`] does.
+    fn from(config: ValueConfig<LENGTH, V>) -> Self {
+        Self::new(config)
+    }
+}
+
+/// Where a raw ADC code falls in a [`Table`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Located {
+    /// The code is within segment `index`, at `adc_value` on the
+    /// table's x axis.
+    Inside { index: usize, adc_value: u32 },
+    /// The code is outside the table, or is implausible or railed,
+    /// with the value given to it by the configuration, if any.
+    Outside(Option<u32>),
+}
+
+/// A reference to a [`Table`], shared by [`AdcInterpolatorRef`]s.
+///
+/// [`AdcInterpolatorRef`]: crate::AdcInterpolatorRef
+pub type TableRef<'a, Word, const LENGTH: usize, V = u32> = &'a Table<Word, LENGTH, V>;
+
+impl<Word, const LENGTH: usize, V> Table<Word, LENGTH, V> {
+    /// Returns a table using the provided `config`.
+    ///
+    /// The values in `config`'s `voltage_to_values` field must be in
+    /// ascending order by voltage or this function will panic when
+    /// running in debug mode, as with
+    /// [`AdcInterpolator::new`](crate::AdcInterpolator::new).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use adc_interpolator::{Config, Table};
+    ///
+    /// let config = Config {
+    ///     max_voltage: 1000,
+    ///     precision: 12,
+    ///     voltage_to_values: [
+    ///         (100, 40),
+    ///         (200, 30),
+    ///         (300, 10),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let table: Table<u16, 3> = Table::new(config);
+    /// ```
+    pub fn new(config: ValueConfig<LENGTH, V>) -> Self
+    where
+        Word: Copy + TryFrom<u32>,
+        V: Copy,
+    {
+        debug_assert!(
+            is_sorted(&config),
+            "The values in table must be in ascending order by voltage"
+        );
+
+        Self::from_codes(config, config.codes().unwrap())
+    }
+
+    pub(crate) fn from_codes(config: ValueConfig<LENGTH, V>, codes: [Word; LENGTH]) -> Self
+    where
+        V: Copy,
+    {
+        const { assert!(LENGTH >= 2, "The table must have at least two points") };
+
+        let (config, values) = config.split();
+
+        Self {
+            sorted: is_sorted(&config),
+            config,
+            values,
+            codes,
+        }
+    }
+
+    // `new` guarantees at compile time that the table has at least
+    // two points, so neither of these can index out of bounds
+
+    pub(crate) fn first_value(&self) -> u32
+    where
+        V: Copy + Into<u32>,
+    {
+        self.values[0].into()
+    }
+
+    pub(crate) fn last_value(&self) -> u32
+    where
+        V: Copy + Into<u32>,
+    {
+        self.values[LENGTH - 1].into()
+    }
+
+    /// Returns `value` clamped to the range of the values of the
+    /// points in the table, if the configuration's `clamp_output` is
+    /// set.
+    pub(crate) fn clamp(&self, value: u32) -> u32
+    where
+        V: Copy + Into<u32>,
+    {
+        if !self.config.clamp_output {
+            return value;
+        }
+
+        let (min, max) = self.value_range();
+
+        value.clamp(min, max)
+    }
+
+    /// Returns the smallest and largest of the values of the points in
+    /// the table, as `(min, max)`.
+    pub(crate) fn value_range(&self) -> (u32, u32)
+    where
+        V: Copy + Into<u32>,
+    {
+        let values = self.values.iter().map(|value| (*value).into());
+
+        values.fold((u32::MAX, u32::MIN), |(min, max), value| {
+            (min.min(value), max.max(value))
+        })
+    }
+
+    #[inline]
+    pub(crate) fn lookup(&self, adc_value: u32) -> Option<u32>
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        self.lookup_with(adc_value, None)
+    }
+
+    /// Like [`lookup`](Table::lookup), but interpolates using `mode`
+    /// instead of the configured mode if it is set.
+    #[inline]
+    pub(crate) fn lookup_with(&self, adc_value: u32, mode: Option<Interpolation>) -> Option<u32>
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        let index = self.segment(adc_value)?;
+
+        Some(self.evaluate(index, adc_value, mode))
+    }
+
+    /// Returns the value at `adc_value` within segment `index`,
+    /// interpolating using `mode` instead of the configured mode if it
+    /// is set.
+    pub(crate) fn evaluate(&self, index: usize, adc_value: u32, mode: Option<Interpolation>) -> u32
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        self.clamp(match mode.unwrap_or_else(|| self.interpolation(index)) {
+            Interpolation::Linear => {
+                let (x0, y0) = self.node(index);
+                let (x1, y1) = self.node(index + 1);
+
+                interpolate(
+                    x0,
+                    x1,
+                    y0.into(),
+                    y1.into(),
+                    adc_value,
+                    self.config.rounding,
+                )
+            }
+            Interpolation::Nearest => self.nearest(index, adc_value).into(),
+            Interpolation::Floor => self.node(index).1.into(),
+            Interpolation::Smoothstep => {
+                let (x0, y0) = self.node(index);
+                let (x1, y1) = self.node(index + 1);
+
+                smoothstep(
+                    x0,
+                    x1,
+                    y0.into(),
+                    y1.into(),
+                    adc_value,
+                    self.config.rounding,
+                )
+            }
+            #[cfg(feature = "float")]
+            Interpolation::CatmullRom => {
+                let (x0, y0) = self.node(index);
+                let (x1, y1) = self.node(index + 1);
+                let (m0, m1) = (self.tangent(index), self.tangent(index + 1));
+
+                hermite(x0, x1, y0.into(), y1.into(), m0, m1, adc_value)
+            }
+        })
+    }
+
+    /// Returns a value based on the table for the raw ADC code `code`,
+    /// after applying all of the corrections in the configuration.
+    pub(crate) fn process(&self, code: i64) -> Option<u32>
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        self.process_with(code, None)
+    }
+
+    /// Like [`process`](Table::process), but interpolates using `mode`
+    /// instead of the configured mode if it is set.
+    pub(crate) fn process_with(&self, code: i64, mode: Option<Interpolation>) -> Option<u32>
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        self.resolve(self.locate(code), mode)
+    }
+
+    /// Returns the value for a code which [`locate`](Table::locate)
+    /// placed at `located`, interpolating using `mode` instead of the
+    /// configured mode if it is set.
+    pub(crate) fn resolve(&self, located: Located, mode: Option<Interpolation>) -> Option<u32>
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        match located {
+            Located::Inside { index, adc_value } => Some(self.evaluate(index, adc_value, mode)),
+            Located::Outside(value) => value,
+        }
+    }
+
+    /// Returns where the raw ADC code `code` falls in the table, after
+    /// applying all of the corrections in the configuration, so that
+    /// every read method treats codes outside the table, and
+    /// implausible or railed codes, the same way.
+    pub(crate) fn locate(&self, code: i64) -> Located
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        if !self.config.plausible(code) || self.config.railed(code) {
+            return Located::Outside(None);
+        }
+
+        let Ok(unsigned) = u32::try_from(code) else {
+            return Located::Outside(self.margin(code, true).or_else(|| self.out_of_range(true)));
+        };
+        self.locate_corrected(self.config.correct(unsigned))
+    }
+
+    /// Like [`locate`](Table::locate), but for a code which has
+    /// already been checked and corrected.
+    pub(crate) fn locate_corrected(&self, corrected: u32) -> Located
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        let adc_value = self.axis(corrected);
+
+        match self.segment(adc_value) {
+            Some(index) => Located::Inside { index, adc_value },
+            None => {
+                let below = adc_value < self.x(0);
+
+                Located::Outside(
+                    self.margin(corrected.into(), below)
+                        .or_else(|| self.out_of_range(below)),
+                )
+            }
+        }
+    }
+
+    /// Returns the value of the first point in the table if `below` is
+    /// set, or the last point otherwise, if the corrected ADC code
+    /// `code` is within the configuration's `clamp_margin` of it.
+    fn margin(&self, code: i64, below: bool) -> Option<u32>
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        if !self.sorted || self.config.clamp_margin == 0 {
+            return None;
+        }
+
+        let (distance, value) = if below {
+            (i64::from(self.code(0)) - code, self.first_value())
+        } else {
+            (code - i64::from(self.code(LENGTH - 1)), self.last_value())
+        };
+
+        (distance <= self.config.clamp_margin.into()).then(|| self.clamp(value))
+    }
+
+    /// Returns the configured value for readings below the range of
+    /// the table if `below` is set, or above it otherwise.
+    pub(crate) fn out_of_range(&self, below: bool) -> Option<u32>
+    where
+        V: Copy + Into<u32>,
+    {
+        if !self.sorted {
+            return None;
+        }
+
+        let (out_of_range, nearest) = if below {
+            (self.config.out_of_range_low, self.first_value())
+        } else {
+            (self.config.out_of_range_high, self.last_value())
+        };
+
+        match out_of_range {
+            OutOfRange::None => None,
+            OutOfRange::Clamp => Some(self.clamp(nearest)),
+            OutOfRange::Value(value) => Some(self.clamp(value)),
+        }
+    }
+
+    /// Returns the Catmull-Rom tangent at the point at `index`, which
+    /// is the slope between the points on either side of it, or of
+    /// the adjacent segment at the ends of the table.
+    #[cfg(feature = "float")]
+    pub(crate) fn tangent(&self, index: usize) -> f32
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        let (x0, y0) = self.node(index.saturating_sub(1));
+        let (x1, y1) = self.node((index + 1).min(LENGTH - 1));
+
+        if x1 == x0 {
+            0.0
+        } else {
+            (y1.into() as f32 - y0.into() as f32) / (x1 - x0) as f32
+        }
+    }
+
+    pub(crate) fn reading(&self, code: Word) -> Reading
+    where
+        Word: Copy + Into<i64>,
+        V: Copy + Into<u32>,
+    {
+        if !self.config.plausible(code.into()) {
+            return Reading::Implausible;
+        }
+
+        let Ok(code) = u32::try_from(code.into()) else {
+            return Reading::Below;
+        };
+
+        if code == 0 || code == self.config.full_scale_code() {
+            return Reading::Saturated;
+        }
+
+        let adc_value = self.axis(self.config.correct(code));
+
+        match self.lookup(adc_value) {
+            Some(value) => Reading::InRange(value),
+            None if adc_value < self.node(0).0 => Reading::Below,
+            None => Reading::Above,
+        }
+    }
+
+    pub(crate) fn select(&self, index: usize, adc_value: u32) -> V
+    where
+        Word: Copy + Into<i64>,
+        V: Copy,
+    {
+        match self.interpolation(index) {
+            Interpolation::Floor => self.node(index).1,
+            _ => self.nearest(index, adc_value),
+        }
+    }
+
+    /// Returns the mode used to interpolate within segment `index`.
+    pub(crate) fn interpolation(&self, index: usize) -> Interpolation {
+        self.config
+            .segment_interpolation
+            .get(index)
+            .copied()
+            .unwrap_or(self.config.interpolation)
+    }
+
+    /// Returns the value of whichever point of segment `index` is
+    /// closest to `adc_value`, preferring the upper point.
+    pub(crate) fn nearest(&self, index: usize, adc_value: u32) -> V
+    where
+        Word: Copy + Into<i64>,
+        V: Copy,
+    {
+        let (x0, y0) = self.node(index);
+        let (x1, y1) = self.node(index + 1);
+
+        if adc_value - x0 < x1 - adc_value {
+            y0
+        } else {
+            y1
+        }
+    }
+
+    /// Returns the index of the segment of the table containing
+    /// `adc_value`, where segment `n` lies between points `n` and
+    /// `n + 1`. If two segments contain it, the configured
+    /// [`Boundary`] decides which is returned.
+    pub(crate) fn segment(&self, adc_value: u32) -> Option<usize>
+    where
+        Word: Copy + Into<i64>,
+    {
+        if !self.sorted {
+            return None;
+        }
+
+        let contains = |index: &usize| {
+            let (x0, x1) = (self.x(*index), self.x(*index + 1));
+
+            // a segment of zero width can't be interpolated across
+            x0 < x1 && adc_value >= x0 && adc_value <= x1
+        };
+
+        match self.config.boundary {
+            Boundary::Lower => (0..LENGTH - 1).find(contains),
+            Boundary::Upper => (0..LENGTH - 1).rev().find(contains),
+        }
+    }
+
+    /// Returns the ADC code `code` after applying any corrections from
+    /// the configuration, or `None` if `code` is negative.
+    pub(crate) fn corrected(&self, code: Word) -> Option<u32>
+    where
+        Word: Copy + Into<i64>,
+    {
+        u32::try_from(code.into())
+            .ok()
+            .map(|code| self.config.correct(code))
+    }
+
+    /// Returns the position of the ADC code `code` on the table's x
+    /// axis, after applying any corrections from the configuration,
+    /// or `None` if `code` is negative.
+    pub(crate) fn position(&self, code: Word) -> Option<u32>
+    where
+        Word: Copy + Into<i64>,
+    {
+        self.corrected(code).map(|code| self.axis(code))
+    }
+
+    /// Returns the position of the corrected ADC code `code` on the
+    /// table's x axis, which is in µV if the configuration's
+    /// `interpolate_in_voltage` is set, or in ADC codes otherwise.
+    pub(crate) fn axis(&self, code: u32) -> u32 {
+        if self.config.interpolate_in_voltage {
+            self.config.microvolts(code)
+        } else {
+            code
+        }
+    }
+
+    /// Returns the ADC code of the point at `index`.
+    pub(crate) fn code(&self, index: usize) -> u32
+    where
+        Word: Copy + Into<i64>,
+    {
+        // the codes in the table were converted from `u32`s
+        self.codes[index].into() as u32
+    }
+
+    /// Returns the position of the point at `index` on the table's x
+    /// axis.
+    pub(crate) fn x(&self, index: usize) -> u32
+    where
+        Word: Copy + Into<i64>,
+    {
+        if self.config.interpolate_in_voltage {
+            self.config.voltage_to_values[index].0.saturating_mul(1000)
+        } else {
+            self.code(index)
+        }
+    }
+
+    /// Returns the position on the table's x axis and value of the
+    /// point at `index`.
+    pub(crate) fn node(&self, index: usize) -> (u32, V)
+    where
+        Word: Copy + Into<i64>,
+        V: Copy,
+    {
+        (self.x(index), self.values[index])
+    }
+}
+
+/// Returns whether the points in `config`'s table are in ascending
+/// order by voltage.
+pub(crate) fn is_sorted<const LENGTH: usize, V>(config: &ValueConfig<LENGTH, V>) -> bool {
+    config
+        .voltage_to_values
+        .windows(2)
+        .all(|w| w[0].0 <= w[1].0)
+}
@@ -0,0 +1,22 @@
+/// A voltage in millivolts, used by
+/// [`ValueConfig::from_millivolts`](crate::ValueConfig::from_millivolts)
+/// so that voltages in other units, such as volts, can't be passed by
+/// mistake.
+///
+/// # Examples
+///
+/// ```
+/// use adc_interpolator::Millivolts;
+///
+/// let supply = Millivolts(3300);
+///
+/// assert_eq!(u32::from(supply), 3300);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Millivolts(pub u32);
+
+impl From<Millivolts> for u32 {
+    fn from(millivolts: Millivolts) -> Self {
+        millivolts.0
+    }
+}
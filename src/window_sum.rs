@@ -0,0 +1,117 @@
+/// A sliding window of the last `K` values read by
+/// [`AdcInterpolator::read_window_sum`](crate::AdcInterpolator::read_window_sum),
+/// and their sum.
+///
+/// # Examples
+///
+/// ```
+/// use adc_interpolator::WindowSum;
+///
+/// let window = WindowSum::<10>::new();
+///
+/// assert_eq!(window.sum(), 0);
+/// assert_eq!(window.len(), 0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WindowSum<const K: usize> {
+    values: [u32; K],
+    next: usize,
+    len: usize,
+    sum: u64,
+}
+
+impl<const K: usize> WindowSum<K> {
+    /// Returns an empty window.
+    pub const fn new() -> Self {
+        Self {
+            values: [0; K],
+            next: 0,
+            len: 0,
+            sum: 0,
+        }
+    }
+
+    /// Returns the sum of the values in the window, saturating at
+    /// `u32::MAX`.
+    pub fn sum(&self) -> u32 {
+        u32::try_from(self.sum).unwrap_or(u32::MAX)
+    }
+
+    /// Returns the number of values in the window, which is `K` once
+    /// `K` values have been added.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether no values have been added to the window.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Adds `value` to the window, replacing the oldest value if the
+    /// window is full.
+    pub(crate) fn push(&mut self, value: u32) {
+        if K == 0 {
+            return;
+        }
+
+        if self.len == K {
+            self.sum -= u64::from(self.values[self.next]);
+        } else {
+            self.len += 1;
+        }
+
+        self.values[self.next] = value;
+        self.sum += u64::from(value);
+        self.next = (self.next + 1) % K;
+    }
+}
+
+impl<const K: usize> Default for WindowSum<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push() {
+        let mut window = WindowSum::<3>::new();
+        assert!(window.is_empty());
+
+        window.push(1);
+        window.push(2);
+        assert_eq!((window.sum(), window.len()), (3, 2));
+
+        window.push(3);
+        window.push(4);
+        assert_eq!((window.sum(), window.len()), (9, 3));
+
+        window.push(10);
+        assert_eq!(window.sum(), 17);
+    }
+
+    #[test]
+    fn saturates() {
+        let mut window = WindowSum::<2>::new();
+
+        window.push(u32::MAX);
+        window.push(u32::MAX);
+        assert_eq!(window.sum(), u32::MAX);
+
+        window.push(0);
+        window.push(1);
+        assert_eq!(window.sum(), 1);
+    }
+
+    #[test]
+    fn empty_window() {
+        let mut window = WindowSum::<0>::new();
+
+        window.push(5);
+        assert_eq!((window.sum(), window.len()), (0, 0));
+    }
+}
@@ -0,0 +1,126 @@
+use crate::error::WordError;
+use core::marker::PhantomData;
+use embedded_hal::adc::{Channel, OneShot};
+
+/// An ADC which converts the words read by another ADC into `i64`s,
+/// for word types which don't convert into an `i64` without loss,
+/// such as `u64`.
+///
+/// An interpolator with an `i64` word can read through the adapter.
+/// A word which doesn't fit in an `i64` is returned as
+/// [`WordError::Conversion`], rather than being truncated.
+///
+/// # Examples
+///
+/// ```
+/// use adc_interpolator::{AdcInterpolator, Config, WordAdapter, WordError};
+/// # use embedded_hal_mock::{
+/// #     adc::{Mock, MockChan0, Transaction},
+/// #     common::Generic,
+/// #     MockError,
+/// # };
+/// #
+/// # let expectations: [Transaction<u64>; 2] = [
+/// #     Transaction::read(0, 614),
+/// #     Transaction::read(0, u64::MAX),
+/// # ];
+/// # let adc = Mock::new(&expectations);
+/// # let pin = MockChan0 {};
+///
+/// let config = Config {
+///     max_voltage: 1000,
+///     precision: 12,
+///     voltage_to_values: [
+///         (100, 40),
+///         (200, 30),
+///         (300, 10),
+///     ],
+///     ..Default::default()
+/// };
+///
+/// let mut interpolator: AdcInterpolator<_, i64, 3> = AdcInterpolator::new(pin, config);
+/// // `adc` reads `u64` words
+/// let mut adc = WordAdapter::new(adc);
+///
+/// assert_eq!(interpolator.read(&mut adc), Ok(Some(35)));
+/// assert_eq!(
+///     interpolator.read(&mut adc),
+///     Err(nb::Error::Other(WordError::Conversion))
+/// );
+/// ```
+#[derive(Debug)]
+pub struct WordAdapter<Adc, Word> {
+    adc: Adc,
+    word: PhantomData<Word>,
+}
+
+impl<Adc, Word> WordAdapter<Adc, Word> {
+    /// Returns an adapter converting the words read by `adc`.
+    pub fn new(adc: Adc) -> Self {
+        Self {
+            adc,
+            word: PhantomData,
+        }
+    }
+
+    /// Destroys the adapter and returns the ADC.
+    pub fn free(self) -> Adc {
+        self.adc
+    }
+}
+
+impl<ADC, Word, Pin, Adc> OneShot<ADC, i64, Pin> for WordAdapter<Adc, Word>
+where
+    Pin: Channel<ADC>,
+    Adc: OneShot<ADC, Word, Pin>,
+    Word: TryInto<i64>,
+{
+    type Error = WordError<Adc::Error>;
+
+    fn read(&mut self, pin: &mut Pin) -> nb::Result<i64, Self::Error> {
+        let word = self
+            .adc
+            .read(pin)
+            .map_err(|error| error.map(WordError::Adc))?;
+
+        word.try_into()
+            .map_err(|_| nb::Error::Other(WordError::Conversion))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_hal_mock::{
+        adc::{Mock, MockChan0, Transaction},
+        MockError,
+    };
+    use std::io::ErrorKind;
+
+    #[test]
+    fn read() {
+        let expectations = [
+            Transaction::read(0, 614u64),
+            Transaction::read(0, i64::MAX as u64),
+            Transaction::read(0, i64::MAX as u64 + 1),
+            Transaction::read(0, 0).with_error(MockError::Io(ErrorKind::InvalidData)),
+        ];
+        let mut adc = WordAdapter::new(Mock::new(&expectations));
+        let mut pin = MockChan0 {};
+
+        assert_eq!(adc.read(&mut pin), Ok(614));
+        assert_eq!(adc.read(&mut pin), Ok(i64::MAX));
+        assert_eq!(
+            adc.read(&mut pin),
+            Err(nb::Error::Other(WordError::Conversion))
+        );
+        assert_eq!(
+            adc.read(&mut pin),
+            Err(nb::Error::Other(WordError::Adc(MockError::Io(
+                ErrorKind::InvalidData
+            ))))
+        );
+
+        adc.free().done();
+    }
+}
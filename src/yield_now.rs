@@ -0,0 +1,54 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A future which is pending the first time it's polled, and ready
+/// the next, so that other tasks can run while an ADC conversion is in
+/// progress.
+#[derive(Debug, Default)]
+pub(crate) struct YieldNow {
+    yielded: bool,
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            return Poll::Ready(());
+        }
+
+        self.yielded = true;
+        context.waker().wake_by_ref();
+
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::task::Waker;
+
+    /// Polls `future` to completion, returning its output along with
+    /// the number of times it was pending.
+    pub(crate) fn block_on<F: Future>(future: F) -> (F::Output, u32) {
+        let mut future = core::pin::pin!(future);
+        let mut context = Context::from_waker(Waker::noop());
+        let mut pending = 0;
+
+        loop {
+            match future.as_mut().poll(&mut context) {
+                Poll::Ready(output) => return (output, pending),
+                Poll::Pending => pending += 1,
+            }
+        }
+    }
+
+    #[test]
+    fn yields_once() {
+        assert_eq!(block_on(YieldNow::default()), ((), 1));
+    }
+}